@@ -0,0 +1,244 @@
+use crate::error::{Error, InternalError};
+use crate::structs::NetUsbPlayInfo;
+use crate::{Method, send_raw};
+
+#[derive(Debug, Clone)]
+pub struct AlbumArt {
+    /// Value of the `Content-Type` header, e.g. `image/jpeg`.
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Fetches an image from the device.
+///
+/// `url` is either a path relative to the device, as found in `albumart_url` and
+/// `thumbnail` fields, or an absolute `http://` url. HTTPS urls are not supported.
+pub fn get_album_art(ip: &str, url: &str) -> Result<AlbumArt, Error> {
+    let (host, port, path) = resolve_url(ip, url)?;
+    let response = send_raw(&host, port, &path, Method::Get, None)?;
+    parse_image_response(&response)
+}
+
+/// Keeps the last fetched album art of the play info and only refetches it
+/// when `albumart_id` or the url change.
+#[derive(Debug, Default)]
+pub struct AlbumArtCache {
+    key: Option<(i32, String)>,
+    art: Option<AlbumArt>,
+}
+
+impl AlbumArtCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the album art of the current track, or `None` if there is none.
+    pub fn get(&mut self, ip: &str, info: &NetUsbPlayInfo) -> Result<Option<&AlbumArt>, Error> {
+        self.get_by_id(ip, info.albumart_id, &info.albumart_url)
+    }
+
+    pub fn get_by_id(
+        &mut self,
+        ip: &str,
+        albumart_id: i32,
+        url: &str,
+    ) -> Result<Option<&AlbumArt>, Error> {
+        if url.is_empty() {
+            self.clear();
+            return Ok(None);
+        }
+
+        let key = (albumart_id, url.to_string());
+        if self.key.as_ref() != Some(&key) {
+            self.art = Some(get_album_art(ip, url)?);
+            self.key = Some(key);
+        }
+
+        Ok(self.art.as_ref())
+    }
+
+    pub fn clear(&mut self) {
+        self.key = None;
+        self.art = None;
+    }
+}
+
+/// Splits `url` into host, port and path, resolving relative paths against the device.
+fn resolve_url(ip: &str, url: &str) -> Result<(String, u16, String), Error> {
+    if url.starts_with("https://") {
+        return Err(Error::InvalidArgument(format!(
+            "HTTPS is not supported: {}",
+            url
+        )));
+    }
+
+    let Some(rest) = url.strip_prefix("http://") else {
+        let path = if url.starts_with('/') {
+            url.to_string()
+        } else {
+            format!("/{}", url)
+        };
+        return Ok((ip.to_string(), 80, path));
+    };
+
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], rest[i..].to_string()),
+        None => (rest, "/".to_string()),
+    };
+
+    match authority.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse()
+                .map_err(|_| Error::InvalidArgument(format!("Invalid port in {}", url)))?;
+            Ok((host.to_string(), port, path))
+        }
+        None => Ok((authority.to_string(), 80, path)),
+    }
+}
+
+fn parse_image_response(response: &[u8]) -> Result<AlbumArt, Error> {
+    let header_end = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| invalid_response("Missing HTTP headers"))?;
+
+    let head = String::from_utf8_lossy(&response[..header_end]);
+    let body = &response[header_end + 4..];
+
+    let mut lines = head.lines();
+    let status: u16 = lines
+        .next()
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid_response("Missing HTTP status"))?;
+
+    if !(200..300).contains(&status) {
+        return Err(InternalError::HttpError(status).into());
+    }
+
+    let mut content_type = String::new();
+    let mut chunked = false;
+
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-type") {
+                content_type = value.to_string();
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+
+    let data = if chunked {
+        decode_chunked(body)?
+    } else {
+        body.to_vec()
+    };
+
+    Ok(AlbumArt { content_type, data })
+}
+
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|w| w == b"\r\n")
+            .ok_or_else(|| invalid_response("Truncated chunk header"))?;
+        let size_line = String::from_utf8_lossy(&body[..line_end]);
+        let size_hex = size_line.split(';').next().unwrap_or("").trim();
+        let size = usize::from_str_radix(size_hex, 16)
+            .map_err(|_| invalid_response("Invalid chunk size"))?;
+
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Ok(data);
+        }
+        if body.len() < size {
+            return Err(invalid_response("Truncated chunk"));
+        }
+
+        data.extend_from_slice(&body[..size]);
+        body = body[size..].strip_prefix(b"\r\n").unwrap_or(&body[size..]);
+    }
+}
+
+fn invalid_response(msg: &str) -> Error {
+    InternalError::invalid_data(msg).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_relative_url() {
+        let (host, port, path) = resolve_url(
+            "192.168.1.10",
+            "/YamahaRemoteControl/AlbumART/AlbumART1.jpg",
+        )
+        .unwrap();
+        assert_eq!(host, "192.168.1.10");
+        assert_eq!(port, 80);
+        assert_eq!(path, "/YamahaRemoteControl/AlbumART/AlbumART1.jpg");
+
+        let (_, _, path) = resolve_url("192.168.1.10", "art.jpg").unwrap();
+        assert_eq!(path, "/art.jpg");
+    }
+
+    #[test]
+    fn resolve_absolute_url() {
+        let (host, port, path) = resolve_url("192.168.1.10", "http://example.com/a/b.png").unwrap();
+        assert_eq!(host, "example.com");
+        assert_eq!(port, 80);
+        assert_eq!(path, "/a/b.png");
+
+        let (host, port, path) = resolve_url("192.168.1.10", "http://example.com").unwrap();
+        assert_eq!(host, "example.com");
+        assert_eq!(port, 80);
+        assert_eq!(path, "/");
+    }
+
+    #[test]
+    fn resolve_url_with_port() {
+        let (host, port, path) =
+            resolve_url("192.168.1.10", "http://192.168.1.20:8080/art?id=3").unwrap();
+        assert_eq!(host, "192.168.1.20");
+        assert_eq!(port, 8080);
+        assert_eq!(path, "/art?id=3");
+
+        assert!(resolve_url("192.168.1.10", "http://example.com:http/a").is_err());
+    }
+
+    #[test]
+    fn resolve_url_rejects_https() {
+        assert!(matches!(
+            resolve_url("192.168.1.10", "https://example.com/a.jpg"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn decode_multiple_chunks() {
+        let body = b"4\r\nWiki\r\n5;name=value\r\npedia\r\n0\r\n\r\n";
+        assert_eq!(decode_chunked(body).unwrap(), b"Wikipedia");
+    }
+
+    #[test]
+    fn decode_truncated_chunk() {
+        assert!(decode_chunked(b"a\r\nshort").is_err());
+        assert!(decode_chunked(b"4\r\nWiki\r\n").is_err());
+    }
+
+    #[test]
+    fn parse_chunked_image_response() {
+        let response =
+            b"HTTP/1.1 200 OK\r\nContent-Type: image/jpeg\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n0\r\n\r\n";
+        let art = parse_image_response(response).unwrap();
+        assert_eq!(art.content_type, "image/jpeg");
+        assert_eq!(art.data, b"abc");
+    }
+}
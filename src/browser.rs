@@ -0,0 +1,212 @@
+use crate::enums::ListControl;
+use crate::error::{Error, InternalError};
+use crate::structs::{ListInfo, ListItem};
+
+/// Largest page `getListInfo` accepts.
+const PAGE_SIZE: u32 = 8;
+
+/// The list the browser operates on, Net/USB only exposes the "main" list.
+const LIST_ID: &str = "main";
+
+/// Stateful browser over `getListInfo`/`setListControl`.
+///
+/// Items are fetched lazily in pages and cached until the browser moves to another list.
+/// The device keeps a single list cursor per input, so other controllers browsing
+/// the same input will invalidate the cache; call [`ListBrowser::refresh`] in that case.
+pub struct ListBrowser<'a> {
+    ip: &'a str,
+    input: String,
+    zone: String,
+    lang: String,
+    menu_layer: u32,
+    menu_name: String,
+    path: Vec<String>,
+    items: Vec<Option<ListItem>>,
+}
+
+impl<'a> ListBrowser<'a> {
+    /// Opens the list the device currently shows for `input`.
+    /// `zone` is the zone that [`ListBrowser::play`] starts playback in.
+    pub fn new(ip: &'a str, input: &str, zone: &str, lang: &str) -> Result<Self, Error> {
+        let mut browser = ListBrowser {
+            ip,
+            input: input.to_string(),
+            zone: zone.to_string(),
+            lang: lang.to_string(),
+            menu_layer: 0,
+            menu_name: String::new(),
+            path: Vec::new(),
+            items: Vec::new(),
+        };
+        browser.refresh()?;
+        Ok(browser)
+    }
+
+    pub fn menu_layer(&self) -> u32 {
+        self.menu_layer
+    }
+
+    pub fn menu_name(&self) -> &str {
+        &self.menu_name
+    }
+
+    /// Names of the entries entered so far, relative to the list the browser was opened at.
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    pub fn len(&self) -> u32 {
+        self.items.len() as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Drops all cached items and re-reads the current list from the device.
+    pub fn refresh(&mut self) -> Result<(), Error> {
+        self.items.clear();
+        let page = self.fetch(0)?;
+        self.store(page);
+        Ok(())
+    }
+
+    /// Returns the item at `index`, fetching its page if needed.
+    pub fn item(&mut self, index: u32) -> Result<&ListItem, Error> {
+        if index >= self.len() {
+            return Err(Error::InvalidArgument(format!(
+                "list index {} is outside of 0..{}",
+                index,
+                self.len()
+            )));
+        }
+
+        if self.items[index as usize].is_none() {
+            let page = self.fetch(index - index % PAGE_SIZE)?;
+            self.store(page);
+        }
+
+        self.items[index as usize].as_ref().ok_or_else(|| {
+            InternalError::invalid_data(&format!("device returned no item at {}", index)).into()
+        })
+    }
+
+    /// Iterates over all items of the current list, fetching pages as it goes.
+    pub fn items(&mut self) -> impl Iterator<Item = Result<ListItem, Error>> + '_ {
+        (0..self.len()).map(move |i| self.item(i).cloned())
+    }
+
+    /// Enters the folder at `index`.
+    pub fn enter(&mut self, index: u32) -> Result<(), Error> {
+        let item = self.item(index)?;
+        if !item.flags().selectable {
+            return Err(Error::InvalidArgument(format!(
+                "{} can not be entered",
+                item.text
+            )));
+        }
+        let name = item.text.clone();
+
+        crate::net_usb_set_list_control(self.ip, LIST_ID, ListControl::Select, Some(index), None)?;
+        self.path.push(name);
+        self.refresh()
+    }
+
+    /// Goes up one level.
+    pub fn back(&mut self) -> Result<(), Error> {
+        crate::net_usb_set_list_control(self.ip, LIST_ID, ListControl::Return, None, None)?;
+        self.path.pop();
+        self.refresh()
+    }
+
+    /// Goes up until the top level of the input is reached.
+    /// Stops early if the device refuses to go up, keeping the remaining path.
+    pub fn to_root(&mut self) -> Result<(), Error> {
+        while self.menu_layer > 0 {
+            let layer = self.menu_layer;
+            self.back()?;
+            // Some inputs refuse to go above a certain layer.
+            if self.menu_layer >= layer {
+                break;
+            }
+        }
+        if self.menu_layer == 0 {
+            self.path.clear();
+        }
+        Ok(())
+    }
+
+    /// Starts playback of the item at `index` in the browser's zone.
+    pub fn play(&mut self, index: u32) -> Result<(), Error> {
+        let item = self.item(index)?;
+        if !item.flags().playable {
+            return Err(Error::InvalidArgument(format!(
+                "{} can not be played",
+                item.text
+            )));
+        }
+
+        crate::net_usb_set_list_control(
+            self.ip,
+            LIST_ID,
+            ListControl::Play,
+            Some(index),
+            Some(&self.zone),
+        )
+    }
+
+    /// Runs a search through the searchable item at `index` and moves into the results.
+    pub fn search(&mut self, index: u32, text: &str) -> Result<(), Error> {
+        let item = self.item(index)?;
+        if !item.flags().searchable {
+            return Err(Error::InvalidArgument(format!(
+                "{} can not be searched",
+                item.text
+            )));
+        }
+
+        crate::net_usb_set_search_string(self.ip, LIST_ID, text, Some(index))?;
+        self.path.push(text.to_string());
+        self.refresh()
+    }
+
+    /// Returns to the top level and enters each entry of `path` by its text.
+    pub fn navigate(&mut self, path: &[&str]) -> Result<(), Error> {
+        self.to_root()?;
+
+        for name in path {
+            let index = self.find(name)?.ok_or_else(|| {
+                Error::InvalidArgument(format!("{} not found in {}", name, self.menu_name))
+            })?;
+            self.enter(index)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finds the index of the first item whose text matches `text`, ignoring case.
+    pub fn find(&mut self, text: &str) -> Result<Option<u32>, Error> {
+        for i in 0..self.len() {
+            if self.item(i)?.text.eq_ignore_ascii_case(text) {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    fn fetch(&self, index: u32) -> Result<ListInfo, Error> {
+        crate::net_usb_get_list_info(self.ip, &self.input, index, PAGE_SIZE, &self.lang)
+    }
+
+    fn store(&mut self, page: ListInfo) {
+        self.menu_layer = page.menu_layer;
+        self.menu_name = page.menu_name;
+        self.items.resize(page.max_line as usize, None);
+
+        for (offset, item) in page.list_info.into_iter().enumerate() {
+            if let Some(slot) = self.items.get_mut(page.index as usize + offset) {
+                *slot = Some(item);
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::enums::{DistributionRole, ServerInfoType};
+use crate::error::Error;
+use crate::structs::{ClientInfoRequest, ServerInfoRequest};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Group id devices use to signal that they are not part of a group.
+const NO_GROUP: &str = "00000000000000000000000000000000";
+
+/// Zone MusicCast Link clients play the distributed audio in. Also assumed for
+/// a server whose distribution info does not report `server_zone`.
+pub(crate) const LINK_ZONE: &str = "main";
+
+/// Links `clients` to the server's `server_zone`, creating a new MusicCast Link
+/// group or extending the one the server already hosts. Returns the group id.
+///
+/// Clients are told about the group first so they accept the server's stream,
+/// then the server is given the client list and distribution is (re)started.
+/// If a step fails, the clients configured so far leave the group again.
+pub fn dist_link(server_ip: &str, server_zone: &str, clients: &[&str]) -> Result<String, Error> {
+    let info = crate::dist_get_distribution_info(server_ip)?;
+    let group_id = if info.role == DistributionRole::Server && !is_no_group(&info.group_id) {
+        info.group_id
+    } else {
+        generate_group_id()
+    };
+
+    let mut linked = Vec::new();
+    let result = link_clients(server_ip, server_zone, clients, &group_id, &mut linked);
+
+    // Release the clients already told about the group, they would wait for a stream otherwise.
+    if result.is_err() {
+        for client in linked {
+            let _ = leave_group(client);
+        }
+    }
+
+    result.map(|_| group_id)
+}
+
+fn link_clients<'a>(
+    server_ip: &str,
+    server_zone: &str,
+    clients: &[&'a str],
+    group_id: &str,
+    linked: &mut Vec<&'a str>,
+) -> Result<(), Error> {
+    for client in clients {
+        crate::dist_set_client_info(
+            client,
+            &ClientInfoRequest {
+                group_id: group_id.to_string(),
+                zone: vec![LINK_ZONE.to_string()],
+                server_ip_address: Some(server_ip.to_string()),
+            },
+        )?;
+        linked.push(client);
+    }
+
+    crate::dist_set_server_info(
+        server_ip,
+        &ServerInfoRequest {
+            group_id: group_id.to_string(),
+            zone: Some(server_zone.to_string()),
+            r#type: Some(ServerInfoType::Add),
+            client_list: clients.iter().map(|c| c.to_string()).collect(),
+        },
+    )?;
+
+    crate::dist_start_distribution(server_ip, 0)
+}
+
+/// Removes one client from the server's group.
+/// The group is dissolved when its last client leaves.
+pub fn dist_unlink(server_ip: &str, client_ip: &str) -> Result<(), Error> {
+    let info = crate::dist_get_distribution_info(server_ip)?;
+    if !info.client_list.iter().any(|c| c.ip_address == client_ip) {
+        return Err(Error::InvalidArgument(format!(
+            "{} is not a client of {}",
+            client_ip, server_ip
+        )));
+    }
+
+    leave_group(client_ip)?;
+    crate::dist_set_server_info(
+        server_ip,
+        &ServerInfoRequest {
+            group_id: info.group_id,
+            zone: None,
+            r#type: Some(ServerInfoType::Remove),
+            client_list: vec![client_ip.to_string()],
+        },
+    )?;
+
+    let remaining = info
+        .client_list
+        .iter()
+        .filter(|c| c.ip_address != client_ip)
+        .count();
+
+    if remaining == 0 {
+        return dist_dissolve(server_ip);
+    }
+
+    crate::dist_start_distribution(server_ip, 0)
+}
+
+/// Releases all clients of the server's group and stops distribution.
+pub fn dist_dissolve(server_ip: &str) -> Result<(), Error> {
+    let info = crate::dist_get_distribution_info(server_ip)?;
+
+    for client in &info.client_list {
+        leave_group(&client.ip_address)?;
+    }
+
+    crate::dist_stop_distribution(server_ip)?;
+    crate::dist_set_server_info(
+        server_ip,
+        &ServerInfoRequest {
+            group_id: String::new(),
+            zone: None,
+            r#type: None,
+            client_list: Vec::new(),
+        },
+    )
+}
+
+fn leave_group(client_ip: &str) -> Result<(), Error> {
+    crate::dist_set_client_info(
+        client_ip,
+        &ClientInfoRequest {
+            group_id: String::new(),
+            zone: vec![LINK_ZONE.to_string()],
+            server_ip_address: None,
+        },
+    )
+}
+
+fn is_no_group(group_id: &str) -> bool {
+    group_id.is_empty() || group_id == NO_GROUP
+}
+
+/// Random 128 bit id as 32 hex digits, the format the Yamaha app uses.
+fn generate_group_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+
+    // RandomState is seeded randomly per instance, which is enough for a group id.
+    let mut halves = [0u64; 2];
+    for half in halves.iter_mut() {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(nanos);
+        *half = hasher.finish();
+    }
+
+    format!("{:016x}{:016x}", halves[0], halves[1])
+}
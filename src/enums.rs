@@ -1,6 +1,21 @@
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Power {
+    On,
+    Standby,
+    /// Only valid as a command, never reported by `getStatus`.
+    Toggle,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -17,16 +32,39 @@ pub enum Playback {
     FastForwardEnd,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackState {
+    Play,
+    Stop,
+    Pause,
+    FastReverse,
+    FastForward,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum Repeat {
     Off,
     One,
     All,
+    #[default]
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum Shuffle {
@@ -34,6 +72,9 @@ pub enum Shuffle {
     On,
     Songs,
     Albums,
+    #[default]
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
@@ -44,3 +85,340 @@ pub enum ListControl {
     Play,
     Return,
 }
+
+/// Mode of both the tone control and the equalizer.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ToneControlMode {
+    Manual,
+    Auto,
+    Bypass,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Serialized through its string id so unknown ids survive a round trip.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(from = "String", into = "String")]
+pub enum SurroundDecoderType {
+    Toggle,
+    Auto,
+    DolbyPl,
+    DolbyPl2xMovie,
+    DolbyPl2xMusic,
+    DolbyPl2xGame,
+    DolbySurround,
+    DtsNeuralX,
+    DtsNeo6Cinema,
+    DtsNeo6Music,
+    /// A decoder type this library does not know, keeping the id sent by the device.
+    #[strum(default)]
+    Other(String),
+}
+
+impl Default for SurroundDecoderType {
+    fn default() -> Self {
+        SurroundDecoderType::Other(String::new())
+    }
+}
+
+impl From<String> for SurroundDecoderType {
+    fn from(id: String) -> Self {
+        id.parse().unwrap_or(SurroundDecoderType::Other(id))
+    }
+}
+
+impl From<SurroundDecoderType> for String {
+    fn from(t: SurroundDecoderType) -> Self {
+        t.to_string()
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LinkControl {
+    Standard,
+    Stability,
+    Speed,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LinkAudioDelay {
+    AudioSync,
+    Balanced,
+    LipSync,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LinkAudioQuality {
+    Compressed,
+    Uncompressed,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Cursor {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Return,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Menu {
+    OnScreen,
+    TopMenu,
+    Menu,
+    Option,
+    Display,
+    Help,
+    Home,
+    Mode,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TunerBand {
+    Am,
+    Fm,
+    Dab,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TunerTuning {
+    Up,
+    Down,
+    Cancel,
+    AutoUp,
+    AutoDown,
+    TpUp,
+    TpDown,
+    Direct,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Next,
+    Previous,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TunerAudioMode {
+    Mono,
+    Stereo,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DabStatus {
+    Idle,
+    Ready,
+    Tuned,
+    NotReady,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DabCategory {
+    Primary,
+    Secondary,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ManagePlay {
+    /// Plays a list entry right away, replacing the queue.
+    PlayNow,
+    /// Inserts a list entry after the current track.
+    PlayNext,
+    /// Appends a list entry to the end of the queue.
+    AddToQueue,
+    /// Removes a queue entry.
+    Remove,
+    /// Moves a queue entry to another position.
+    Move,
+    /// Empties the queue.
+    Clear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum McPlaylistAction {
+    Play,
+    Add,
+    Delete,
+    Move,
+    Rename,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceInfoType {
+    AccountList,
+    Licensing,
+    ActivationCode,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum StreamingQuality {
+    #[strum(serialize = "mp3_128")]
+    #[serde(rename = "mp3_128")]
+    Mp3128,
+    #[strum(serialize = "mp3_320")]
+    #[serde(rename = "mp3_320")]
+    Mp3320,
+    Normal,
+    High,
+    Cd,
+    Flac,
+    Lossless,
+    HiRes,
+    #[strum(serialize = "hi_res_96")]
+    #[serde(rename = "hi_res_96")]
+    HiRes96,
+    #[strum(serialize = "hi_res_192")]
+    #[serde(rename = "hi_res_192")]
+    HiRes192,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CdDeviceStatus {
+    Open,
+    Close,
+    Ready,
+    NotReady,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DistributionRole {
+    Server,
+    Client,
+    /// Not part of a MusicCast Link group, reported as `none`.
+    #[strum(serialize = "none")]
+    #[serde(rename = "none")]
+    Standalone,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ServerInfoType {
+    Add,
+    Remove,
+}
+
+/// MusicCast Surround pairing, matching the flags of `McRole`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Display, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum McPairType {
+    /// Two rear speakers.
+    SurroundPair,
+    /// Two speakers playing left and right.
+    StereoPair,
+    SubwooferPair,
+    /// A single rear speaker on one side.
+    SurroundPairLOrR,
+    /// A single speaker playing both rear channels.
+    SurroundPairLr,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PartyVolume {
+    Up,
+    Down,
+}
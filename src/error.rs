@@ -6,6 +6,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::ResponseError(code) => write!(f, "Yamaha Device Error: {:?}", code),
+            Error::InvalidArgument(msg) => write!(f, "Invalid Argument: {}", msg),
             Error::InternalError(e) => write!(f, "Internal Error: {}", e),
         }
     }
@@ -18,12 +19,20 @@ impl fmt::Display for InternalError {
         match self {
             InternalError::DeserializationError(e) => write!(f, "JSON Error: {}", e),
             InternalError::IoError(e) => write!(f, "IO Error: {}", e),
+            InternalError::HttpError(status) => write!(f, "HTTP Error: {}", status),
         }
     }
 }
 
 impl std::error::Error for InternalError {}
 
+impl InternalError {
+    /// The device answered, but not with what the protocol promises.
+    pub(crate) fn invalid_data(msg: &str) -> Self {
+        InternalError::IoError(std::io::Error::new(std::io::ErrorKind::InvalidData, msg))
+    }
+}
+
 impl From<std::io::Error> for InternalError {
     fn from(err: std::io::Error) -> Self {
         InternalError::IoError(err)
@@ -45,6 +54,8 @@ impl From<InternalError> for Error {
 #[derive(Debug)]
 pub enum Error {
     ResponseError(ResponseCode),
+    /// Returned before any request is sent, when a value is not supported by the device.
+    InvalidArgument(String),
     InternalError(InternalError),
 }
 
@@ -53,6 +64,8 @@ pub enum Error {
 pub enum InternalError {
     DeserializationError(serde_json::Error),
     IoError(std::io::Error),
+    /// Non-success HTTP status, only returned by calls outside of the JSON API.
+    HttpError(u16),
 }
 
 impl Serialize for InternalError {
@@ -64,6 +77,7 @@ impl Serialize for InternalError {
             match self {
                 InternalError::DeserializationError(e) => format!("DeserializationError: {}", e),
                 InternalError::IoError(e) => format!("IoError: {}", e),
+                InternalError::HttpError(status) => format!("HttpError: {}", status),
             }
             .as_str(),
         )
@@ -78,6 +92,7 @@ impl Serialize for Error {
         serializer.serialize_str(
             match self {
                 Error::ResponseError(e) => format!("ResponseError: {}", e),
+                Error::InvalidArgument(e) => format!("InvalidArgument: {}", e),
                 Error::InternalError(e) => serde_json::to_string(e).unwrap(),
             }
             .as_str(),
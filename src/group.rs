@@ -0,0 +1,283 @@
+use crate::distribution::LINK_ZONE;
+use crate::enums::{DistributionRole, Power};
+use crate::error::Error;
+use crate::structs::ZoneStatus;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMember {
+    pub ip: String,
+    pub zone: String,
+}
+
+/// Status of one member, or the error reading it.
+#[derive(Debug)]
+pub struct MemberState {
+    pub member: GroupMember,
+    pub status: Result<ZoneStatus, Error>,
+}
+
+/// A set of zones controlled as one, e.g. the members of a MusicCast Link group.
+///
+/// Commands are sent to every member even if one of them fails,
+/// the first error is returned afterwards. Members whose status can not be read
+/// are skipped by the reading methods, which only fail when no member could be read.
+#[derive(Debug, Clone)]
+pub struct Group {
+    members: Vec<GroupMember>,
+}
+
+impl Group {
+    pub fn new(members: Vec<GroupMember>) -> Self {
+        Group { members }
+    }
+
+    /// Builds the group hosted by `server_ip` from its distribution info.
+    pub fn from_server(server_ip: &str) -> Result<Self, Error> {
+        let info = crate::dist_get_distribution_info(server_ip)?;
+        if info.role != DistributionRole::Server {
+            return Err(Error::InvalidArgument(format!(
+                "{} is not the server of a group",
+                server_ip
+            )));
+        }
+
+        let mut members = vec![GroupMember {
+            ip: server_ip.to_string(),
+            zone: info.server_zone.unwrap_or_else(|| LINK_ZONE.to_string()),
+        }];
+        members.extend(info.client_list.into_iter().map(|c| GroupMember {
+            ip: c.ip_address,
+            zone: LINK_ZONE.to_string(),
+        }));
+
+        Ok(Group { members })
+    }
+
+    pub fn members(&self) -> &[GroupMember] {
+        &self.members
+    }
+
+    /// Reads the zone status of every member.
+    pub fn states(&self) -> Vec<MemberState> {
+        self.members
+            .iter()
+            .map(|m| MemberState {
+                member: m.clone(),
+                status: crate::get_zone_status(&m.ip, &m.zone),
+            })
+            .collect()
+    }
+
+    /// Group volume from 0.0 to 1.0, the average of each member's volume relative to its `max_volume`.
+    pub fn volume(&self) -> Result<f32, Error> {
+        let statuses = readable(self.states())?;
+        let pairs: Vec<_> = statuses
+            .iter()
+            .map(|(_, s)| (s.volume, s.max_volume))
+            .collect();
+        Ok(group_level(&pairs))
+    }
+
+    /// Sets the group volume from 0.0 to 1.0.
+    ///
+    /// Members keep their volume relative to each other: each one is scaled by the same
+    /// factor, so a quieter room stays quieter. Silent members stay silent until the others
+    /// reach their maximum, the rest of the change is then shared between them.
+    ///
+    /// Members whose status can not be read are skipped, the others are still set.
+    pub fn set_volume(&self, level: f32) -> Result<(), Error> {
+        let (statuses, first_err) = split(self.states());
+        let pairs: Vec<_> = statuses
+            .iter()
+            .map(|(_, s)| (s.volume, s.max_volume))
+            .collect();
+        let targets = scale_volumes(&pairs, level);
+        let members: Vec<_> = statuses.iter().map(|(m, _)| m).zip(targets).collect();
+        let result = for_each(&members, |(m, volume)| {
+            crate::set_volume(&m.ip, &m.zone, *volume as i32)
+        });
+
+        match first_err {
+            Some(e) => Err(e),
+            None => result,
+        }
+    }
+
+    pub fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        for_each(&self.members, |m| crate::set_mute(&m.ip, &m.zone, mute))
+    }
+
+    /// Reports the group as muted only when every readable member is muted.
+    pub fn is_muted(&self) -> Result<bool, Error> {
+        Ok(readable(self.states())?.iter().all(|(_, s)| s.mute))
+    }
+
+    pub fn set_power(&self, power: Power) -> Result<(), Error> {
+        for_each(&self.members, |m| crate::set_power(&m.ip, &m.zone, power))
+    }
+}
+
+/// Runs `f` for every item, returning the first error once all of them were tried.
+fn for_each<T>(items: &[T], mut f: impl FnMut(&T) -> Result<(), Error>) -> Result<(), Error> {
+    let mut first_err = None;
+    for item in items {
+        if let Err(e) = f(item) {
+            first_err.get_or_insert(e);
+        }
+    }
+    first_err.map_or(Ok(()), Err)
+}
+
+/// Keeps the members whose status could be read, failing with the first error if none could.
+fn readable(states: Vec<MemberState>) -> Result<Vec<(GroupMember, ZoneStatus)>, Error> {
+    match split(states) {
+        (statuses, Some(e)) if statuses.is_empty() => Err(e),
+        (statuses, _) => Ok(statuses),
+    }
+}
+
+/// Separates the readable members from the first error.
+fn split(states: Vec<MemberState>) -> (Vec<(GroupMember, ZoneStatus)>, Option<Error>) {
+    let mut first_err = None;
+    let mut statuses = Vec::new();
+    for state in states {
+        match state.status {
+            Ok(status) => statuses.push((state.member, status)),
+            Err(e) => {
+                first_err.get_or_insert(e);
+            }
+        }
+    }
+    (statuses, first_err)
+}
+
+fn member_level((volume, max_volume): (u32, u32)) -> f32 {
+    if max_volume == 0 {
+        return 0.0;
+    }
+    (volume as f32 / max_volume as f32).clamp(0.0, 1.0)
+}
+
+fn group_level(members: &[(u32, u32)]) -> f32 {
+    if members.is_empty() {
+        return 0.0;
+    }
+    members.iter().map(|&m| member_level(m)).sum::<f32>() / members.len() as f32
+}
+
+/// Computes the new `volume` of each `(volume, max_volume)` pair so that the group level becomes `level`.
+///
+/// Every member is scaled by the same factor, members reaching their `max_volume` are held
+/// there and the others take the rest. Silent members can not be scaled, they stay silent
+/// while the others can take the change and share whatever is left once all others are at
+/// their maximum.
+/// A fully silent group is set to `level` everywhere.
+fn scale_volumes(members: &[(u32, u32)], level: f32) -> Vec<u32> {
+    let level = level.clamp(0.0, 1.0);
+    let current = group_level(members);
+    let levels: Vec<f32> = members.iter().map(|&m| member_level(m)).collect();
+
+    let targets: Vec<f32> = if current == 0.0 {
+        vec![level; members.len()]
+    } else {
+        let wanted = level * members.len() as f32;
+
+        // Members reaching their maximum are held there and the factor is recomputed
+        // for the others until none of them goes over.
+        let mut capped = vec![false; levels.len()];
+        let factor = loop {
+            let free: f32 = levels
+                .iter()
+                .zip(&capped)
+                .filter(|(_, capped)| !**capped)
+                .map(|(own, _)| own)
+                .sum();
+            let room = wanted - capped.iter().filter(|c| **c).count() as f32;
+            let factor = if free > 0.0 { room / free } else { 0.0 };
+
+            let mut changed = false;
+            for (own, capped) in levels.iter().zip(capped.iter_mut()) {
+                if !*capped && *own > 0.0 && own * factor >= 1.0 {
+                    *capped = true;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break factor;
+            }
+        };
+
+        let mut targets: Vec<f32> = levels
+            .iter()
+            .zip(&capped)
+            .map(|(own, capped)| if *capped { 1.0 } else { own * factor })
+            .collect();
+
+        let silent = levels.iter().filter(|&&own| own == 0.0).count();
+        let missing = level * members.len() as f32 - targets.iter().sum::<f32>();
+        if silent > 0 && missing > 0.0 {
+            let share = (missing / silent as f32).min(1.0);
+            for (target, own) in targets.iter_mut().zip(&levels) {
+                if *own == 0.0 {
+                    *target = share;
+                }
+            }
+        }
+        targets
+    };
+
+    targets
+        .iter()
+        .zip(members)
+        .map(|(target, &(_, max_volume))| (target * max_volume as f32).round() as u32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_keeps_relative_volumes() {
+        assert_eq!(scale_volumes(&[(80, 100), (40, 100)], 0.3), vec![40, 20]);
+        assert_eq!(scale_volumes(&[(40, 100), (20, 50)], 0.6), vec![60, 30]);
+    }
+
+    #[test]
+    fn scale_hands_capped_volume_to_the_others() {
+        assert_eq!(scale_volumes(&[(90, 100), (30, 100)], 0.9), vec![100, 80]);
+    }
+
+    #[test]
+    fn scale_leaves_silent_members_while_others_can_take_it() {
+        assert_eq!(scale_volumes(&[(80, 100), (0, 100)], 0.41), vec![82, 0]);
+        assert_eq!(scale_volumes(&[(80, 100), (0, 100)], 0.2), vec![40, 0]);
+    }
+
+    #[test]
+    fn scale_gives_the_rest_to_silent_members() {
+        assert_eq!(scale_volumes(&[(90, 100), (0, 100)], 0.8), vec![100, 60]);
+        assert_eq!(
+            scale_volumes(&[(90, 100), (0, 100), (0, 100)], 1.0),
+            vec![100, 100, 100]
+        );
+    }
+
+    #[test]
+    fn scale_silent_group() {
+        assert_eq!(scale_volumes(&[(0, 100), (0, 60)], 0.5), vec![50, 30]);
+        assert_eq!(scale_volumes(&[], 0.5), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn scale_keeps_average_at_level() {
+        let members = [(30, 100), (0, 100), (90, 100)];
+        for level in [0.0, 0.1, 0.25, 0.5, 0.75, 1.0] {
+            let pairs: Vec<_> = scale_volumes(&members, level)
+                .into_iter()
+                .map(|v| (v, 100))
+                .collect();
+            assert!((group_level(&pairs) - level).abs() < 0.01, "{}", level);
+        }
+    }
+}
@@ -1,6 +1,12 @@
+mod album_art;
+mod browser;
 mod discover;
+mod distribution;
 pub mod enums;
 pub mod error;
+mod group;
+mod now_playing;
+mod play_queue;
 mod structs;
 
 use crate::enums::*;
@@ -8,26 +14,35 @@ use serde::de::DeserializeOwned;
 use std::{
     io::{Read, Write},
     net::{TcpStream, ToSocketAddrs},
-    time::Duration,
+    thread,
+    time::{Duration, Instant},
 };
 
+pub use crate::album_art::{AlbumArt, AlbumArtCache, get_album_art};
+pub use crate::browser::ListBrowser;
 pub use crate::discover::discover_yamaha_devices;
+pub use crate::distribution::{dist_dissolve, dist_link, dist_unlink};
 use crate::error::{Error, InternalError};
+pub use crate::group::{Group, GroupMember, MemberState};
+pub use crate::now_playing::NowPlaying;
+pub use crate::play_queue::{PlayQueueIter, net_usb_play_queue_iter};
 pub use crate::structs::*;
 use serde::Serialize;
 
-enum Method {
+pub(crate) enum Method {
     Get,
     Post,
 }
 
-fn send_request(
+/// Sends a request to `host:port` and returns the raw response, headers included.
+pub(crate) fn send_raw(
     host: &str,
+    port: u16,
     path: &str,
     method: Method,
     body_json: Option<String>,
-) -> Result<String, InternalError> {
-    let addr = (host, 80).to_socket_addrs()?.next().ok_or_else(|| {
+) -> Result<Vec<u8>, InternalError> {
+    let addr = (host, port).to_socket_addrs()?.next().ok_or_else(|| {
         std::io::Error::new(
             std::io::ErrorKind::AddrNotAvailable,
             "Failed to resolve host",
@@ -44,7 +59,7 @@ fn send_request(
     };
 
     let mut request = format!(
-        "{} /YamahaExtendedControl{} HTTP/1.1\r\n\
+        "{} {} HTTP/1.1\r\n\
          Host: {}\r\n\
          Connection: close\r\n",
         method_str, path, host
@@ -64,6 +79,23 @@ fn send_request(
     let mut buffer = Vec::new();
     stream.read_to_end(&mut buffer)?;
 
+    Ok(buffer)
+}
+
+fn send_request(
+    host: &str,
+    path: &str,
+    method: Method,
+    body_json: Option<String>,
+) -> Result<String, InternalError> {
+    let buffer = send_raw(
+        host,
+        80,
+        &format!("/YamahaExtendedControl{}", path),
+        method,
+        body_json,
+    )?;
+
     let response_str = String::from_utf8_lossy(&buffer);
 
     if let Some(body_start) = response_str.find("\r\n\r\n") {
@@ -147,6 +179,29 @@ pub fn get_zone_status(ip: &str, zone: &str) -> Result<ZoneStatus, Error> {
     yamaha_req!(ip, format!("/v1/{}/getStatus", zone), ZoneStatus)
 }
 
+pub fn get_name_text(ip: &str, id: Option<&str>) -> Result<NameText, Error> {
+    match id {
+        Some(id) => yamaha_req!(ip, format!("/v1/system/getNameText?id={}", id), NameText),
+        None => yamaha_req!(ip, "/v1/system/getNameText", NameText),
+    }
+}
+
+/// Renames a zone, input, sound program or scene.
+pub fn set_name_text(ip: &str, id: &str, text: &str) -> Result<(), Error> {
+    let req_body = NameTextRequest {
+        id: id.to_string(),
+        text: text.to_string(),
+    };
+
+    yamaha_post_req!(ip, "/v1/system/setNameText", req_body)
+}
+
+/// Validates the input's `rename_enable` flag before calling [`set_name_text`].
+pub fn set_name_text_checked(ip: &str, system: &System, id: &str, text: &str) -> Result<(), Error> {
+    system.validate_rename(id)?;
+    set_name_text(ip, id, text)
+}
+
 pub fn get_zone_program_list(ip: &str, zone: &str) -> Result<ZoneProgramList, Error> {
     yamaha_req!(
         ip,
@@ -163,8 +218,109 @@ pub fn net_usb_get_play_info(ip: &str) -> Result<NetUsbPlayInfo, Error> {
     yamaha_req!(ip, "/v1/netusb/getPlayInfo", NetUsbPlayInfo)
 }
 
+pub fn set_power(ip: &str, zone: &str, power: Power) -> Result<(), Error> {
+    if power == Power::Unknown {
+        return Err(Error::InvalidArgument(
+            "power can not be unknown".to_string(),
+        ));
+    }
+    yamaha_req!(ip, format!("/v1/{}/setPower?power={}", zone, power))
+}
+
 pub fn toggle_zone_power(ip: &str, zone: &str) -> Result<(), Error> {
-    yamaha_req!(ip, format!("/v1/{}/setPower?power=toggle", zone))
+    set_power(ip, zone, Power::Toggle)
+}
+
+/// Powers the zone on and polls `getStatus` until it reports `on` and the device
+/// stops answering with `Initializing`, or until `timeout` runs out.
+pub fn power_on_and_wait(ip: &str, zone: &str, timeout: Duration) -> Result<ZoneStatus, Error> {
+    let deadline = Instant::now() + timeout;
+    let mut power_sent = false;
+
+    loop {
+        let attempt = if power_sent {
+            get_zone_status(ip, zone).map(Some)
+        } else {
+            set_power(ip, zone, Power::On).map(|_| None)
+        };
+
+        match attempt {
+            Ok(Some(status)) if status.power == Power::On => return Ok(status),
+            // Always read the status at least once, even when the timeout is already over.
+            Ok(None) => {
+                power_sent = true;
+                continue;
+            }
+            Ok(Some(_)) | Err(Error::ResponseError(ResponseCode::Initializing)) => {}
+            Err(e) => return Err(e),
+        }
+
+        if Instant::now() >= deadline {
+            return Err(InternalError::IoError(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "Zone did not power on in time",
+            ))
+            .into());
+        }
+
+        thread::sleep(Duration::from_millis(500));
+    }
+}
+
+pub fn recall_scene(ip: &str, zone: &str, num: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/{}/recallScene?num={}", zone, num))
+}
+
+/// Validates `num` against the zone's `scene_num` before calling [`recall_scene`].
+pub fn recall_scene_checked(ip: &str, zone: &Zone, num: u32) -> Result<(), Error> {
+    zone.validate_scene(num)?;
+    recall_scene(ip, &zone.id, num)
+}
+
+/// Lists the scenes of a zone, with names where the firmware exposes them through `getNameText`.
+pub fn get_zone_scenes(ip: &str, zone: &Zone) -> Result<Vec<Scene>, Error> {
+    let count = zone.scene_num.unwrap_or(0).max(0) as u32;
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Not every firmware supports getNameText, scenes are still usable without names.
+    let names = match get_name_text(ip, None) {
+        Ok(names) => names,
+        Err(Error::ResponseError(ResponseCode::InvalidRequest)) => NameText::default(),
+        Err(e) => return Err(e),
+    };
+
+    Ok((1..=count)
+        .map(|num| Scene {
+            num,
+            name: names.scene_name(&zone.id, num).map(str::to_string),
+        })
+        .collect())
+}
+
+pub fn control_cursor(ip: &str, zone: &str, cursor: Cursor) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/{}/controlCursor?cursor={}", zone, cursor))
+}
+
+/// Validates the key against `cursor_list` before calling [`control_cursor`].
+pub fn control_cursor_checked(ip: &str, zone: &Zone, cursor: Cursor) -> Result<(), Error> {
+    zone.validate_in_list(
+        "cursor_list",
+        zone.cursor_list.as_ref(),
+        &cursor.to_string(),
+    )?;
+    control_cursor(ip, &zone.id, cursor)
+}
+
+pub fn control_menu(ip: &str, zone: &str, menu: Menu) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/{}/controlMenu?menu={}", zone, menu))
+}
+
+/// Validates the key against `menu_list` before calling [`control_menu`].
+pub fn control_menu_checked(ip: &str, zone: &Zone, menu: Menu) -> Result<(), Error> {
+    zone.validate_in_list("menu_list", zone.menu_list.as_ref(), &menu.to_string())?;
+    control_menu(ip, &zone.id, menu)
 }
 
 pub fn set_sleep(ip: &str, zone: &str, time: u32) -> Result<(), Error> {
@@ -214,6 +370,132 @@ pub fn set_balance(ip: &str, zone: &str, balance: i32) -> Result<(), Error> {
     yamaha_req!(ip, format!("/v1/{}/setBalance?value={}", zone, balance))
 }
 
+pub fn set_balance_checked(ip: &str, zone: &Zone, balance: i32) -> Result<(), Error> {
+    zone.validate_range("balance", balance as f32)?;
+    set_balance(ip, &zone.id, balance)
+}
+
+pub fn set_surround_decoder_type(
+    ip: &str,
+    zone: &str,
+    decoder_type: SurroundDecoderType,
+) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setSurroundDecoderType?type={}", zone, decoder_type)
+    )
+}
+
+/// Validates the decoder type against `surr_decoder_type_list` before calling [`set_surround_decoder_type`].
+pub fn set_surround_decoder_type_checked(
+    ip: &str,
+    zone: &Zone,
+    decoder_type: SurroundDecoderType,
+) -> Result<(), Error> {
+    zone.validate_in_list(
+        "surr_decoder_type_list",
+        zone.surr_decoder_type_list.as_ref(),
+        &decoder_type.to_string(),
+    )?;
+    set_surround_decoder_type(ip, &zone.id, decoder_type)
+}
+
+pub fn set_adaptive_drc(ip: &str, zone: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/{}/setAdaptiveDrc?enable={}", zone, enable))
+}
+
+pub fn set_adaptive_dsp_level(ip: &str, zone: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setAdaptiveDspLevel?enable={}", zone, enable)
+    )
+}
+
+pub fn set_clear_voice(ip: &str, zone: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/{}/setClearVoice?enable={}", zone, enable))
+}
+
+pub fn set_bass_extension(ip: &str, zone: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setBassExtension?enable={}", zone, enable)
+    )
+}
+
+pub fn set_surround_ai(ip: &str, zone: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/{}/setSurroundAI?enable={}", zone, enable))
+}
+
+pub fn set_contents_display(ip: &str, zone: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setContentsDisplay?enable={}", zone, enable)
+    )
+}
+
+pub fn set_link_control(ip: &str, zone: &str, control: LinkControl) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setLinkControl?control={}", zone, control)
+    )
+}
+
+/// Validates the value against `link_control_list` before calling [`set_link_control`].
+pub fn set_link_control_checked(ip: &str, zone: &Zone, control: LinkControl) -> Result<(), Error> {
+    zone.validate_in_list(
+        "link_control_list",
+        zone.link_control_list.as_ref(),
+        &control.to_string(),
+    )?;
+    set_link_control(ip, &zone.id, control)
+}
+
+pub fn set_link_audio_delay(ip: &str, zone: &str, delay: LinkAudioDelay) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setLinkAudioDelay?delay={}", zone, delay)
+    )
+}
+
+/// Validates the value against `link_audio_delay_list` before calling [`set_link_audio_delay`].
+pub fn set_link_audio_delay_checked(
+    ip: &str,
+    zone: &Zone,
+    delay: LinkAudioDelay,
+) -> Result<(), Error> {
+    zone.validate_in_list(
+        "link_audio_delay_list",
+        zone.link_audio_delay_list.as_ref(),
+        &delay.to_string(),
+    )?;
+    set_link_audio_delay(ip, &zone.id, delay)
+}
+
+pub fn set_link_audio_quality(
+    ip: &str,
+    zone: &str,
+    quality: LinkAudioQuality,
+) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/{}/setLinkAudioQuality?mode={}", zone, quality)
+    )
+}
+
+/// Validates the value against `link_audio_quality_list` before calling [`set_link_audio_quality`].
+pub fn set_link_audio_quality_checked(
+    ip: &str,
+    zone: &Zone,
+    quality: LinkAudioQuality,
+) -> Result<(), Error> {
+    zone.validate_in_list(
+        "link_audio_quality_list",
+        zone.link_audio_quality_list.as_ref(),
+        &quality.to_string(),
+    )?;
+    set_link_audio_quality(ip, &zone.id, quality)
+}
+
 pub fn set_extra_bass(ip: &str, zone: &str, bass: bool) -> Result<(), Error> {
     yamaha_req!(ip, format!("/v1/{}/setExtraBass?enable={}", zone, bass))
 }
@@ -223,10 +505,20 @@ pub fn net_usb_set_playback(ip: &str, playback: Playback) -> Result<(), Error> {
 }
 
 pub fn net_usb_set_repeat(ip: &str, mode: Repeat) -> Result<(), Error> {
+    if mode == Repeat::Unknown {
+        return Err(Error::InvalidArgument(
+            "mode can not be unknown".to_string(),
+        ));
+    }
     yamaha_req!(ip, format!("/v1/netusb/setRepeat?mode={}", mode))
 }
 
 pub fn net_usb_set_shuffle(ip: &str, mode: Shuffle) -> Result<(), Error> {
+    if mode == Shuffle::Unknown {
+        return Err(Error::InvalidArgument(
+            "mode can not be unknown".to_string(),
+        ));
+    }
     yamaha_req!(ip, format!("/v1/netusb/setShuffle?mode={}", mode))
 }
 
@@ -293,11 +585,292 @@ pub fn net_usb_set_list_control(
     yamaha_req!(ip, url)
 }
 
+pub fn net_usb_get_preset_info(ip: &str) -> Result<NetUsbPresetInfo, Error> {
+    yamaha_req!(ip, "/v1/netusb/getPresetInfo", NetUsbPresetInfo)
+}
+
+pub fn net_usb_recall_preset(ip: &str, zone: &str, num: u32) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/netusb/recallPreset?zone={}&num={}", zone, num)
+    )
+}
+
+/// Validates `num` against the preset count from features before calling [`net_usb_recall_preset`].
+pub fn net_usb_recall_preset_checked(
+    ip: &str,
+    zone: &str,
+    net_usb: &NetUsb,
+    num: u32,
+) -> Result<(), Error> {
+    net_usb.preset.validate(num)?;
+    net_usb_recall_preset(ip, zone, num)
+}
+
+/// Stores the currently playing content in preset `num`.
+pub fn net_usb_store_preset(ip: &str, num: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/netusb/storePreset?num={}", num))
+}
+
+pub fn net_usb_clear_preset(ip: &str, num: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/netusb/clearPreset?num={}", num))
+}
+
+pub fn net_usb_move_preset(ip: &str, from: u32, to: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/netusb/movePreset?from={}&to={}", from, to))
+}
+
+pub fn net_usb_get_play_queue(ip: &str, index: u32, size: u32) -> Result<PlayQueue, Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/netusb/getPlayQueue?index={}&size={}", index, size),
+        PlayQueue
+    )
+}
+
+pub fn net_usb_manage_play(ip: &str, req: &ManagePlayRequest) -> Result<(), Error> {
+    let mut url = format!("/v1/netusb/managePlay?type={}", req.r#type);
+
+    if let Some(list_id) = &req.list_id {
+        url.push_str(&format!("&list_id={}", list_id));
+    }
+
+    if let Some(idx) = req.index {
+        url.push_str(&format!("&index={}", idx));
+    }
+
+    if let Some(to) = req.to_index {
+        url.push_str(&format!("&to_index={}", to));
+    }
+
+    if let Some(z) = &req.zone {
+        url.push_str(&format!("&zone={}", z));
+    }
+
+    yamaha_req!(ip, url)
+}
+
+/// Plays, plays next or queues the list entry at `index`.
+pub fn net_usb_play_list_item(
+    ip: &str,
+    zone: &str,
+    list_id: &str,
+    index: u32,
+    action: ManagePlay,
+) -> Result<(), Error> {
+    net_usb_manage_play(
+        ip,
+        &ManagePlayRequest {
+            r#type: action,
+            list_id: Some(list_id.to_string()),
+            index: Some(index),
+            to_index: None,
+            zone: Some(zone.to_string()),
+        },
+    )
+}
+
+pub fn net_usb_play_queue_remove(ip: &str, index: u32) -> Result<(), Error> {
+    net_usb_manage_play(
+        ip,
+        &ManagePlayRequest {
+            r#type: ManagePlay::Remove,
+            list_id: None,
+            index: Some(index),
+            to_index: None,
+            zone: None,
+        },
+    )
+}
+
+pub fn net_usb_play_queue_move(ip: &str, from: u32, to: u32) -> Result<(), Error> {
+    net_usb_manage_play(
+        ip,
+        &ManagePlayRequest {
+            r#type: ManagePlay::Move,
+            list_id: None,
+            index: Some(from),
+            to_index: Some(to),
+            zone: None,
+        },
+    )
+}
+
+pub fn net_usb_play_queue_clear(ip: &str) -> Result<(), Error> {
+    net_usb_manage_play(
+        ip,
+        &ManagePlayRequest {
+            r#type: ManagePlay::Clear,
+            list_id: None,
+            index: None,
+            to_index: None,
+            zone: None,
+        },
+    )
+}
+
+pub fn net_usb_get_mc_playlist_name(ip: &str) -> Result<McPlaylistNames, Error> {
+    yamaha_req!(ip, "/v1/netusb/getMcPlaylistName", McPlaylistNames)
+}
+
+pub fn net_usb_get_mc_playlist(ip: &str, bank: u32, index: u32) -> Result<McPlaylist, Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/netusb/getMcPlaylist?bank={}&index={}", bank, index),
+        McPlaylist
+    )
+}
+
+pub fn net_usb_manage_mc_playlist(ip: &str, req: &McPlaylistRequest) -> Result<(), Error> {
+    yamaha_post_req!(ip, "/v1/netusb/manageMcPlaylist", req)
+}
+
+/// Starts playing playlist `bank` from entry `index`.
+pub fn net_usb_play_mc_playlist(ip: &str, zone: &str, bank: u32, index: u32) -> Result<(), Error> {
+    let mut req = McPlaylistRequest::new(bank, McPlaylistAction::Play);
+    req.index = Some(index);
+    req.zone = Some(zone.to_string());
+    net_usb_manage_mc_playlist(ip, &req)
+}
+
+/// Adds the entry at `index` of the browsed list `list_id` to playlist `bank`.
+pub fn net_usb_add_to_mc_playlist(
+    ip: &str,
+    bank: u32,
+    list_id: &str,
+    index: u32,
+) -> Result<(), Error> {
+    let mut req = McPlaylistRequest::new(bank, McPlaylistAction::Add);
+    req.list_id = Some(list_id.to_string());
+    req.index = Some(index);
+    net_usb_manage_mc_playlist(ip, &req)
+}
+
+pub fn net_usb_delete_from_mc_playlist(ip: &str, bank: u32, index: u32) -> Result<(), Error> {
+    let mut req = McPlaylistRequest::new(bank, McPlaylistAction::Delete);
+    req.index = Some(index);
+    net_usb_manage_mc_playlist(ip, &req)
+}
+
+pub fn net_usb_move_in_mc_playlist(ip: &str, bank: u32, from: u32, to: u32) -> Result<(), Error> {
+    let mut req = McPlaylistRequest::new(bank, McPlaylistAction::Move);
+    req.index = Some(from);
+    req.to_index = Some(to);
+    net_usb_manage_mc_playlist(ip, &req)
+}
+
+pub fn net_usb_rename_mc_playlist(ip: &str, bank: u32, name: &str) -> Result<(), Error> {
+    let mut req = McPlaylistRequest::new(bank, McPlaylistAction::Rename);
+    req.name = Some(name.to_string());
+    net_usb_manage_mc_playlist(ip, &req)
+}
+
+pub fn net_usb_get_account_status(ip: &str) -> Result<AccountStatus, Error> {
+    yamaha_req!(ip, "/v1/netusb/getAccountStatus", AccountStatus)
+}
+
+pub fn net_usb_get_service_info(
+    ip: &str,
+    input: &str,
+    info_type: ServiceInfoType,
+) -> Result<ServiceInfo, Error> {
+    yamaha_req!(
+        ip,
+        format!(
+            "/v1/netusb/getServiceInfo?input={}&type={}",
+            input, info_type
+        ),
+        ServiceInfo
+    )
+}
+
+/// Switches `input` to the account at `index` of its `account_list`.
+/// `timeout` is in milliseconds.
+pub fn net_usb_switch_account(
+    ip: &str,
+    input: &str,
+    index: u32,
+    timeout: Option<u32>,
+) -> Result<(), Error> {
+    let mut url = format!("/v1/netusb/switchAccount?input={}&index={}", input, index);
+
+    if let Some(t) = timeout {
+        url.push_str(&format!("&timeout={}", t));
+    }
+
+    yamaha_req!(ip, url)
+}
+
+pub fn net_usb_get_settings(ip: &str) -> Result<NetUsbSettings, Error> {
+    yamaha_req!(ip, "/v1/netusb/getSettings", NetUsbSettings)
+}
+
+pub fn net_usb_set_quality(ip: &str, input: &str, quality: StreamingQuality) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/netusb/setQuality?input={}&value={}", input, quality)
+    )
+}
+
+/// Validates the quality against the service's `quality_list` before calling [`net_usb_set_quality`].
+pub fn net_usb_set_quality_checked(
+    ip: &str,
+    settings: &NetUsbSettings,
+    input: &str,
+    quality: StreamingQuality,
+) -> Result<(), Error> {
+    // Unknown entries of the list can not be sent back to the device.
+    let supported = quality != StreamingQuality::Unknown
+        && settings
+            .quality(input)
+            .is_some_and(|q| q.quality_list.contains(&quality));
+
+    if !supported {
+        return Err(Error::InvalidArgument(format!(
+            "{} does not support {} quality",
+            input, quality
+        )));
+    }
+
+    net_usb_set_quality(ip, input, quality)
+}
+
+pub fn net_usb_get_play_description(ip: &str) -> Result<PlayDescription, Error> {
+    yamaha_req!(ip, "/v1/netusb/getPlayDescription", PlayDescription)
+}
+
 pub fn set_volume(ip: &str, zone: &str, volume: i32) -> Result<(), Error> {
     yamaha_req!(ip, format!("/v1/{}/setVolume?volume={}", zone, volume))
 }
 
-pub fn set_actual_volume(ip: &str, zone: &str, mode: &str, volume: i32) -> Result<(), Error> {
+/// Sets the volume after validating it against the zone's `volume` range.
+pub fn set_volume_checked(ip: &str, zone: &Zone, volume: i32) -> Result<(), Error> {
+    zone.validate_range("volume", volume as f32)?;
+    set_volume(ip, &zone.id, volume)
+}
+
+/// Sets the volume as a 0.0 - 100.0 percentage of the zone's `volume` range.
+pub fn set_volume_percent(ip: &str, zone: &Zone, percent: f32) -> Result<(), Error> {
+    let volume = zone
+        .percent_to_volume(percent)
+        .ok_or_else(|| Error::InvalidArgument(format!("zone {} has no volume range", zone.id)))?;
+    set_volume(ip, &zone.id, volume)
+}
+
+/// Sets the volume in dB. Uses `setActualVolume` when the zone supports the `db` mode,
+/// otherwise converts to the nearest raw volume step.
+pub fn set_volume_db(ip: &str, zone: &Zone, db: f32) -> Result<(), Error> {
+    if zone.supports_actual_volume_mode("db") {
+        let db = zone.validate_range("actual_volume_db", db)?;
+        return set_actual_volume(ip, &zone.id, "db", db);
+    }
+    let volume = zone.db_to_volume(db).ok_or_else(|| {
+        Error::InvalidArgument(format!("zone {} has no dB volume range", zone.id))
+    })?;
+    set_volume(ip, &zone.id, volume)
+}
+
+pub fn set_actual_volume(ip: &str, zone: &str, mode: &str, volume: f32) -> Result<(), Error> {
     yamaha_req!(
         ip,
         format!(
@@ -311,6 +884,31 @@ pub fn set_ypao_volume(ip: &str, enabled: bool) -> Result<(), Error> {
     yamaha_req!(ip, format!("/v1/system/setYpaoVolume?enable={}", enabled))
 }
 
+pub fn set_party_mode(ip: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/system/setPartyMode?enable={}", enable))
+}
+
+pub fn set_party_volume(ip: &str, volume: PartyVolume) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/system/setPartyVolume?volume={}", volume))
+}
+
+/// Validates the mode against the system's `party_volume_list` before calling [`set_party_volume`].
+pub fn set_party_volume_checked(
+    ip: &str,
+    system: &System,
+    volume: PartyVolume,
+) -> Result<(), Error> {
+    system.validate_party_volume(volume)?;
+    set_party_volume(ip, volume)
+}
+
+pub fn set_party_mute_enable(ip: &str, enable: bool) -> Result<(), Error> {
+    yamaha_req!(
+        ip,
+        format!("/v1/system/setPartyMuteEnable?enable={}", enable)
+    )
+}
+
 pub fn get_ypao_config(ip: &str) -> Result<YpaoConfig, Error> {
     yamaha_req!(ip, "/v1/system/getYpaoConfig", YpaoConfig)
 }
@@ -322,6 +920,11 @@ pub fn set_subwoofer_volume(ip: &str, zone: &str, volume: i32) -> Result<(), Err
     )
 }
 
+pub fn set_subwoofer_volume_checked(ip: &str, zone: &Zone, volume: i32) -> Result<(), Error> {
+    zone.validate_range("subwoofer_volume", volume as f32)?;
+    set_subwoofer_volume(ip, &zone.id, volume)
+}
+
 pub fn set_dialogue_lift(ip: &str, zone: &str, value: i32) -> Result<(), Error> {
     yamaha_req!(ip, format!("/v1/{}/setDialogueLift?value={}", zone, value))
 }
@@ -330,6 +933,11 @@ pub fn set_dialogue_level(ip: &str, zone: &str, value: i32) -> Result<(), Error>
     yamaha_req!(ip, format!("/v1/{}/setDialogueLevel?value={}", zone, value))
 }
 
+pub fn set_dialogue_level_checked(ip: &str, zone: &Zone, value: i32) -> Result<(), Error> {
+    zone.validate_range("dialogue_level", value as f32)?;
+    set_dialogue_level(ip, &zone.id, value)
+}
+
 pub fn set_dts_dialogue_control(ip: &str, zone: &str, value: i32) -> Result<(), Error> {
     yamaha_req!(
         ip,
@@ -337,20 +945,148 @@ pub fn set_dts_dialogue_control(ip: &str, zone: &str, value: i32) -> Result<(),
     )
 }
 
-pub fn set_tone_bass(ip: &str, zone: &str, bass: i32) -> Result<(), Error> {
+pub fn set_tone_control(ip: &str, zone: &str, tone: &ToneControlRequest) -> Result<(), Error> {
+    let mut params = Vec::new();
+
+    if let Some(mode) = tone.mode {
+        if mode == ToneControlMode::Unknown {
+            return Err(Error::InvalidArgument(
+                "mode can not be unknown".to_string(),
+            ));
+        }
+        params.push(format!("mode={}", mode));
+    }
+
+    if let Some(bass) = tone.bass {
+        params.push(format!("bass={}", bass));
+    }
+
+    if let Some(treble) = tone.treble {
+        params.push(format!("treble={}", treble));
+    }
+
+    if params.is_empty() {
+        return Err(Error::InvalidArgument(
+            "at least one field must be set".to_string(),
+        ));
+    }
+
     yamaha_req!(
         ip,
-        format!("/v1/{}/setToneControl?mode=manual&bass={}", zone, bass)
+        format!("/v1/{}/setToneControl?{}", zone, params.join("&"))
+    )
+}
+
+/// Validates the mode against `tone_control_mode_list` and the levels against
+/// the `tone_control` range before calling [`set_tone_control`].
+pub fn set_tone_control_checked(
+    ip: &str,
+    zone: &Zone,
+    tone: &ToneControlRequest,
+) -> Result<(), Error> {
+    if let Some(mode) = tone.mode {
+        zone.validate_in_list(
+            "tone_control_mode_list",
+            zone.tone_control_mode_list.as_ref(),
+            &mode.to_string(),
+        )?;
+    }
+
+    for value in [tone.bass, tone.treble].into_iter().flatten() {
+        zone.validate_range("tone_control", value as f32)?;
+    }
+
+    set_tone_control(ip, &zone.id, tone)
+}
+
+pub fn set_tone_bass(ip: &str, zone: &str, bass: i32) -> Result<(), Error> {
+    set_tone_control(
+        ip,
+        zone,
+        &ToneControlRequest {
+            mode: Some(ToneControlMode::Manual),
+            bass: Some(bass),
+            treble: None,
+        },
     )
 }
 
 pub fn set_tone_treble(ip: &str, zone: &str, treble: i32) -> Result<(), Error> {
+    set_tone_control(
+        ip,
+        zone,
+        &ToneControlRequest {
+            mode: Some(ToneControlMode::Manual),
+            bass: None,
+            treble: Some(treble),
+        },
+    )
+}
+
+pub fn set_tone_bass_checked(ip: &str, zone: &Zone, bass: i32) -> Result<(), Error> {
+    zone.validate_range("tone_control", bass as f32)?;
+    set_tone_bass(ip, &zone.id, bass)
+}
+
+pub fn set_tone_treble_checked(ip: &str, zone: &Zone, treble: i32) -> Result<(), Error> {
+    zone.validate_range("tone_control", treble as f32)?;
+    set_tone_treble(ip, &zone.id, treble)
+}
+
+pub fn set_equalizer(ip: &str, zone: &str, eq: &EqualizerRequest) -> Result<(), Error> {
+    let mut params = Vec::new();
+
+    if let Some(mode) = eq.mode {
+        if mode == ToneControlMode::Unknown {
+            return Err(Error::InvalidArgument(
+                "mode can not be unknown".to_string(),
+            ));
+        }
+        params.push(format!("mode={}", mode));
+    }
+
+    if let Some(low) = eq.low {
+        params.push(format!("low={}", low));
+    }
+
+    if let Some(mid) = eq.mid {
+        params.push(format!("mid={}", mid));
+    }
+
+    if let Some(high) = eq.high {
+        params.push(format!("high={}", high));
+    }
+
+    if params.is_empty() {
+        return Err(Error::InvalidArgument(
+            "at least one field must be set".to_string(),
+        ));
+    }
+
     yamaha_req!(
         ip,
-        format!("/v1/{}/setToneControl?mode=manual&treble={}", zone, treble)
+        format!("/v1/{}/setEqualizer?{}", zone, params.join("&"))
     )
 }
 
+/// Validates the mode against `equalizer_mode_list` and the levels against
+/// the `equalizer` range before calling [`set_equalizer`].
+pub fn set_equalizer_checked(ip: &str, zone: &Zone, eq: &EqualizerRequest) -> Result<(), Error> {
+    if let Some(mode) = eq.mode {
+        zone.validate_in_list(
+            "equalizer_mode_list",
+            zone.equalizer_mode_list.as_ref(),
+            &mode.to_string(),
+        )?;
+    }
+
+    for value in [eq.low, eq.mid, eq.high].into_iter().flatten() {
+        zone.validate_range("equalizer", value as f32)?;
+    }
+
+    set_equalizer(ip, &zone.id, eq)
+}
+
 pub fn net_usb_get_recent_info(ip: &str) -> Result<RecentInfo, Error> {
     yamaha_req!(ip, "/v1/netusb/getRecentInfo", RecentInfo)
 }
@@ -371,3 +1107,218 @@ pub fn net_usb_recall_recent(ip: &str, zone: &str, recent_info_index: u32) -> Re
         )
     )
 }
+
+pub fn tuner_get_play_info(ip: &str) -> Result<TunerPlayInfo, Error> {
+    yamaha_req!(ip, "/v1/tuner/getPlayInfo", TunerPlayInfo)
+}
+
+/// Rejects [`TunerBand::Unknown`], which only stands for bands this library doesn't know.
+fn check_tuner_band(band: TunerBand) -> Result<(), Error> {
+    if band == TunerBand::Unknown {
+        return Err(Error::InvalidArgument(
+            "band can not be unknown".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn tuner_set_band(ip: &str, band: TunerBand) -> Result<(), Error> {
+    check_tuner_band(band)?;
+    yamaha_req!(ip, format!("/v1/tuner/setBand?band={}", band))
+}
+
+/// `freq` is in kHz and only used with [`TunerTuning::Direct`].
+pub fn tuner_set_freq(
+    ip: &str,
+    band: TunerBand,
+    tuning: TunerTuning,
+    freq: Option<u32>,
+) -> Result<(), Error> {
+    check_tuner_band(band)?;
+    let mut url = format!("/v1/tuner/setFreq?band={}&tuning={}", band, tuning);
+
+    if let Some(f) = freq {
+        url.push_str(&format!("&num={}", f));
+    }
+
+    yamaha_req!(ip, url)
+}
+
+/// Validates the band against the tuner's `func_list` and a direct frequency
+/// against its `range_step` before calling [`tuner_set_freq`].
+pub fn tuner_set_freq_checked(
+    ip: &str,
+    tuner: &Tuner,
+    band: TunerBand,
+    tuning: TunerTuning,
+    freq: Option<u32>,
+) -> Result<(), Error> {
+    if !tuner.supports_band(band) {
+        return Err(Error::InvalidArgument(format!(
+            "tuner does not support {}",
+            band
+        )));
+    }
+
+    if tuning == TunerTuning::Direct {
+        let freq = freq.ok_or_else(|| {
+            Error::InvalidArgument("direct tuning requires a frequency".to_string())
+        })?;
+        tuner.validate_freq(band, freq)?;
+    }
+
+    tuner_set_freq(ip, band, tuning, freq)
+}
+
+/// Switches to the next or previous DAB service.
+pub fn tuner_set_dab_service(ip: &str, dir: Direction) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/tuner/setDabService?dir={}", dir))
+}
+
+/// Band parameter of the preset calls, `None` stands for `common` presets.
+fn tuner_preset_band(band: Option<TunerBand>) -> Result<String, Error> {
+    match band {
+        Some(b) => {
+            check_tuner_band(b)?;
+            Ok(b.to_string())
+        }
+        None => Ok("common".to_string()),
+    }
+}
+
+pub fn tuner_get_preset_info(ip: &str, band: Option<TunerBand>) -> Result<TunerPresetInfo, Error> {
+    let band = tuner_preset_band(band)?;
+    yamaha_req!(
+        ip,
+        format!("/v1/tuner/getPresetInfo?band={}", band),
+        TunerPresetInfo
+    )
+}
+
+pub fn tuner_recall_preset(
+    ip: &str,
+    zone: &str,
+    band: Option<TunerBand>,
+    num: u32,
+) -> Result<(), Error> {
+    let band = tuner_preset_band(band)?;
+    yamaha_req!(
+        ip,
+        format!(
+            "/v1/tuner/recallPreset?zone={}&band={}&num={}",
+            zone, band, num
+        )
+    )
+}
+
+/// Validates the band and number against the tuner's preset features before calling [`tuner_recall_preset`].
+pub fn tuner_recall_preset_checked(
+    ip: &str,
+    zone: &str,
+    tuner: &Tuner,
+    band: Option<TunerBand>,
+    num: u32,
+) -> Result<(), Error> {
+    tuner.preset.validate(band, num)?;
+    tuner_recall_preset(ip, zone, band, num)
+}
+
+pub fn tuner_switch_preset(ip: &str, dir: Direction) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/tuner/switchPreset?dir={}", dir))
+}
+
+/// Stores the currently tuned station in preset `num`.
+pub fn tuner_store_preset(ip: &str, num: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/tuner/storePreset?num={}", num))
+}
+
+pub fn tuner_clear_preset(ip: &str, band: Option<TunerBand>, num: u32) -> Result<(), Error> {
+    let band = tuner_preset_band(band)?;
+    yamaha_req!(
+        ip,
+        format!("/v1/tuner/clearPreset?band={}&num={}", band, num)
+    )
+}
+
+pub fn tuner_move_preset(
+    ip: &str,
+    band: Option<TunerBand>,
+    from: u32,
+    to: u32,
+) -> Result<(), Error> {
+    let band = tuner_preset_band(band)?;
+    yamaha_req!(
+        ip,
+        format!("/v1/tuner/movePreset?band={}&from={}&to={}", band, from, to)
+    )
+}
+
+pub fn cd_get_play_info(ip: &str) -> Result<CdPlayInfo, Error> {
+    yamaha_req!(ip, "/v1/cd/getPlayInfo", CdPlayInfo)
+}
+
+pub fn cd_set_playback(ip: &str, playback: Playback) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/cd/setPlayback?playback={}", playback))
+}
+
+pub fn cd_toggle_tray(ip: &str) -> Result<(), Error> {
+    yamaha_req!(ip, "/v1/cd/toggleTray")
+}
+
+pub fn cd_set_repeat(ip: &str, mode: Repeat) -> Result<(), Error> {
+    if mode == Repeat::Unknown {
+        return Err(Error::InvalidArgument(
+            "mode can not be unknown".to_string(),
+        ));
+    }
+    yamaha_req!(ip, format!("/v1/cd/setRepeat?mode={}", mode))
+}
+
+pub fn cd_set_shuffle(ip: &str, mode: Shuffle) -> Result<(), Error> {
+    if mode == Shuffle::Unknown {
+        return Err(Error::InvalidArgument(
+            "mode can not be unknown".to_string(),
+        ));
+    }
+    yamaha_req!(ip, format!("/v1/cd/setShuffle?mode={}", mode))
+}
+
+pub fn cd_toggle_repeat(ip: &str) -> Result<(), Error> {
+    yamaha_req!(ip, "/v1/cd/toggleRepeat")
+}
+
+pub fn cd_toggle_shuffle(ip: &str) -> Result<(), Error> {
+    yamaha_req!(ip, "/v1/cd/toggleShuffle")
+}
+
+pub fn cd_set_play_position(ip: &str, position: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/cd/setPlayPosition?position={}", position))
+}
+
+pub fn dist_get_distribution_info(ip: &str) -> Result<DistributionInfo, Error> {
+    yamaha_req!(ip, "/v1/dist/getDistributionInfo", DistributionInfo)
+}
+
+pub fn dist_set_server_info(ip: &str, req: &ServerInfoRequest) -> Result<(), Error> {
+    yamaha_post_req!(ip, "/v1/dist/setServerInfo", req)
+}
+
+pub fn dist_set_client_info(ip: &str, req: &ClientInfoRequest) -> Result<(), Error> {
+    yamaha_post_req!(ip, "/v1/dist/setClientInfo", req)
+}
+
+pub fn dist_start_distribution(ip: &str, num: u32) -> Result<(), Error> {
+    yamaha_req!(ip, format!("/v1/dist/startDistribution?num={}", num))
+}
+
+pub fn dist_stop_distribution(ip: &str) -> Result<(), Error> {
+    yamaha_req!(ip, "/v1/dist/stopDistribution")
+}
+
+pub fn dist_set_group_name(ip: &str, name: &str) -> Result<(), Error> {
+    let req_body = GroupNameRequest {
+        name: name.to_string(),
+    };
+
+    yamaha_post_req!(ip, "/v1/dist/setGroupName", req_body)
+}
@@ -0,0 +1,177 @@
+use crate::enums::PlaybackState;
+use crate::error::Error;
+use crate::structs::NetUsbPlayInfo;
+use std::time::{Duration, Instant};
+
+/// Tracks the Net/USB now playing state between polls.
+///
+/// `play_time` is only as fresh as the last `getPlayInfo`, so while playing the
+/// position is interpolated with the local clock to drive a smooth progress bar.
+#[derive(Debug, Default)]
+pub struct NowPlaying {
+    info: Option<NetUsbPlayInfo>,
+    updated_at: Option<Instant>,
+}
+
+impl NowPlaying {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn info(&self) -> Option<&NetUsbPlayInfo> {
+        self.info.as_ref()
+    }
+
+    /// Fetches the play info and feeds it to [`NowPlaying::update`].
+    pub fn poll(&mut self, ip: &str) -> Result<bool, Error> {
+        let info = crate::net_usb_get_play_info(ip)?;
+        Ok(self.update(info))
+    }
+
+    /// Stores a fresh play info, e.g. from polling or an event.
+    /// Returns true when the track changed.
+    pub fn update(&mut self, info: NetUsbPlayInfo) -> bool {
+        let changed = self
+            .info
+            .as_ref()
+            .is_none_or(|old| is_other_track(old, &info));
+        self.info = Some(info);
+        self.updated_at = Some(Instant::now());
+        changed
+    }
+
+    /// Applies a `play_time` update, as sent in events, without a full play info.
+    pub fn set_play_time(&mut self, play_time: i32) {
+        if let Some(info) = &mut self.info {
+            info.play_time = play_time;
+            self.updated_at = Some(Instant::now());
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.info
+            .as_ref()
+            .is_some_and(|i| i.playback == PlaybackState::Play)
+    }
+
+    /// Interpolated playback position.
+    pub fn position(&self) -> Duration {
+        self.position_at(Instant::now())
+    }
+
+    pub fn position_at(&self, now: Instant) -> Duration {
+        let (Some(info), Some(updated_at)) = (&self.info, self.updated_at) else {
+            return Duration::ZERO;
+        };
+
+        let mut position = Duration::from_secs(info.play_time.max(0) as u64);
+        if info.playback == PlaybackState::Play {
+            position += now.saturating_duration_since(updated_at);
+        }
+
+        match self.duration() {
+            Some(total) => position.min(total),
+            None => position,
+        }
+    }
+
+    /// Length of the track, `None` for streams without a known length.
+    pub fn duration(&self) -> Option<Duration> {
+        self.info
+            .as_ref()
+            .filter(|i| i.total_time > 0)
+            .map(|i| Duration::from_secs(i.total_time as u64))
+    }
+
+    /// Interpolated progress from 0.0 to 1.0, `None` if the track length is unknown.
+    pub fn progress(&self) -> Option<f32> {
+        let total = self.duration()?;
+        Some(self.position().as_secs_f32() / total.as_secs_f32())
+    }
+}
+
+fn is_other_track(old: &NetUsbPlayInfo, new: &NetUsbPlayInfo) -> bool {
+    old.input != new.input
+        || old.artist != new.artist
+        || old.album != new.album
+        || old.track != new.track
+        || old.albumart_id != new.albumart_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(playback: PlaybackState, play_time: i32, total_time: i32) -> NetUsbPlayInfo {
+        NetUsbPlayInfo {
+            playback,
+            play_time,
+            total_time,
+            artist: "Artist".to_string(),
+            album: "Album".to_string(),
+            track: "Track".to_string(),
+            albumart_id: 1,
+            ..NetUsbPlayInfo::default()
+        }
+    }
+
+    fn now_playing(info: NetUsbPlayInfo) -> (NowPlaying, Instant) {
+        let mut now_playing = NowPlaying::new();
+        now_playing.update(info);
+        let updated_at = now_playing.updated_at.unwrap();
+        (now_playing, updated_at)
+    }
+
+    #[test]
+    fn position_advances_only_while_playing() {
+        let (playing, at) = now_playing(info(PlaybackState::Play, 10, 200));
+        assert_eq!(
+            playing.position_at(at + Duration::from_secs(5)),
+            Duration::from_secs(15)
+        );
+
+        for state in [PlaybackState::Pause, PlaybackState::Stop] {
+            let (paused, at) = now_playing(info(state, 10, 200));
+            assert_eq!(
+                paused.position_at(at + Duration::from_secs(5)),
+                Duration::from_secs(10)
+            );
+        }
+    }
+
+    #[test]
+    fn position_is_clamped_to_total_time() {
+        let (playing, at) = now_playing(info(PlaybackState::Play, 195, 200));
+        assert_eq!(
+            playing.position_at(at + Duration::from_secs(30)),
+            Duration::from_secs(200)
+        );
+
+        let (stream, at) = now_playing(info(PlaybackState::Play, 195, 0));
+        assert_eq!(
+            stream.position_at(at + Duration::from_secs(30)),
+            Duration::from_secs(225)
+        );
+    }
+
+    #[test]
+    fn update_reports_track_changes() {
+        let (mut now_playing, _) = now_playing(info(PlaybackState::Play, 10, 200));
+
+        assert!(!now_playing.update(info(PlaybackState::Play, 42, 200)));
+        assert!(!now_playing.update(info(PlaybackState::Pause, 42, 200)));
+
+        let changes: [fn(&mut NetUsbPlayInfo); 4] = [
+            |i| i.artist = "Other".to_string(),
+            |i| i.album = "Other".to_string(),
+            |i| i.track = "Other".to_string(),
+            |i| i.albumart_id = 2,
+        ];
+        for change in changes {
+            now_playing.update(info(PlaybackState::Play, 0, 200));
+            let mut other = info(PlaybackState::Play, 0, 200);
+            change(&mut other);
+            assert!(now_playing.update(other));
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::error::Error;
+use crate::structs::PlayQueueEntry;
+use std::collections::VecDeque;
+
+/// Largest page the devices reliably return in one `getPlayQueue` call.
+const PAGE_SIZE: u32 = 8;
+
+/// Walks the whole play queue, fetching it page by page as needed.
+/// Yields each entry together with its queue index.
+pub struct PlayQueueIter<'a> {
+    ip: &'a str,
+    index: u32,
+    max_line: Option<u32>,
+    buffer: VecDeque<PlayQueueEntry>,
+    done: bool,
+}
+
+pub fn net_usb_play_queue_iter(ip: &str) -> PlayQueueIter<'_> {
+    PlayQueueIter::from_index(ip, 0)
+}
+
+impl<'a> PlayQueueIter<'a> {
+    pub fn from_index(ip: &'a str, index: u32) -> Self {
+        PlayQueueIter {
+            ip,
+            index,
+            max_line: None,
+            buffer: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Total number of queue entries, known after the first page was fetched.
+    pub fn max_line(&self) -> Option<u32> {
+        self.max_line
+    }
+
+    fn fetch_page(&mut self) -> Result<(), Error> {
+        let page = crate::net_usb_get_play_queue(self.ip, self.index, PAGE_SIZE)?;
+        self.max_line = Some(page.max_line);
+        if page.track_info.is_empty() {
+            self.done = true;
+        }
+        self.buffer.extend(page.track_info);
+        Ok(())
+    }
+}
+
+impl Iterator for PlayQueueIter<'_> {
+    type Item = Result<(u32, PlayQueueEntry), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() {
+            if self.done || self.max_line.is_some_and(|max| self.index >= max) {
+                return None;
+            }
+            if let Err(e) = self.fetch_page() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+
+        let entry = self.buffer.pop_front()?;
+        let index = self.index;
+        self.index += 1;
+        Some(Ok((index, entry)))
+    }
+}
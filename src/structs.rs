@@ -2,6 +2,9 @@ use std::{fmt, net::IpAddr};
 
 use serde::{Deserialize, Serialize};
 
+use crate::enums::*;
+use crate::error::Error;
+
 #[derive(Deserialize, Serialize, Debug)]
 pub enum ResponseCode {
     Successful = 0,
@@ -132,7 +135,7 @@ pub struct AnalyticsInfo {
 
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct ZoneStatus {
-    pub power: String,
+    pub power: Power,
     pub sleep: u32,
     pub volume: u32,
     pub mute: bool,
@@ -141,15 +144,17 @@ pub struct ZoneStatus {
     pub input_text: String,
     pub distribution_enable: bool,
     pub sound_program: String,
-    pub surr_decoder_type: String,
+    pub surr_decoder_type: SurroundDecoderType,
     pub pure_direct: bool,
     pub enhancer: bool,
     pub tone_control: ToneControl,
+    pub equalizer: Option<Equalizer>,
     pub dialogue_level: u32,
     pub dialogue_lift: u32,
     pub subwoofer_volume: u32,
-    pub link_control: String,
-    pub link_audio_delay: String,
+    pub link_control: LinkControl,
+    pub link_audio_delay: LinkAudioDelay,
+    pub link_audio_quality: Option<LinkAudioQuality>,
     pub disable_flags: u32,
     pub contents_display: bool,
     pub actual_volume: ActualVolume,
@@ -158,13 +163,41 @@ pub struct ZoneStatus {
     pub adaptive_drc: bool,
     pub dts_dialogue_control: u32,
     pub adaptive_dsp_level: bool,
+    pub clear_voice: Option<bool>,
+    pub bass_extension: Option<bool>,
+    pub surround_ai: Option<bool>,
 }
 
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct ToneControl {
-    pub mode: String,
-    pub bass: u32,
-    pub treble: u32,
+    pub mode: ToneControlMode,
+    pub bass: i32,
+    pub treble: i32,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug)]
+pub struct Equalizer {
+    pub mode: ToneControlMode,
+    pub low: i32,
+    pub mid: i32,
+    pub high: i32,
+}
+
+/// Parameters for `setToneControl`, unset fields are left unchanged by the device.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ToneControlRequest {
+    pub mode: Option<ToneControlMode>,
+    pub bass: Option<i32>,
+    pub treble: Option<i32>,
+}
+
+/// Parameters for `setEqualizer`, unset fields are left unchanged by the device.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EqualizerRequest {
+    pub mode: Option<ToneControlMode>,
+    pub low: Option<i32>,
+    pub mid: Option<i32>,
+    pub high: Option<i32>,
 }
 
 #[derive(Deserialize, Serialize, Default, Debug)]
@@ -174,6 +207,13 @@ pub struct ActualVolume {
     pub unit: String,
 }
 
+impl ActualVolume {
+    /// Returns the volume in dB, if the device reported it in dB mode.
+    pub fn db(&self) -> Option<f32> {
+        (self.mode == "db").then_some(self.value)
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct ZoneProgramList {
     pub sound_program_list: Vec<String>,
@@ -215,6 +255,34 @@ pub struct System {
     pub works_with_sonos: Option<WorksWithSonos>,
 }
 
+impl System {
+    /// Checks that `id` is not an input that has renaming disabled.
+    pub fn validate_rename(&self, id: &str) -> Result<(), Error> {
+        match self.input_list.iter().find(|i| i.id == id) {
+            Some(input) if !input.rename_enable => Err(Error::InvalidArgument(format!(
+                "input {} can not be renamed",
+                id
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate_party_volume(&self, volume: PartyVolume) -> Result<(), Error> {
+        let volume = volume.to_string();
+        if self
+            .party_volume_list
+            .as_ref()
+            .is_some_and(|l| l.contains(&volume))
+        {
+            return Ok(());
+        }
+        Err(Error::InvalidArgument(format!(
+            "{} is not in the party_volume_list",
+            volume
+        )))
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct SystemInput {
     pub id: String,
@@ -250,8 +318,10 @@ pub struct Zone {
     pub sound_program_list: Option<Vec<String>>,
     pub surr_decoder_type_list: Option<Vec<String>>,
     pub tone_control_mode_list: Option<Vec<String>>,
+    pub equalizer_mode_list: Option<Vec<String>>,
     pub link_control_list: Option<Vec<String>>,
     pub link_audio_delay_list: Option<Vec<String>>,
+    pub link_audio_quality_list: Option<Vec<String>>,
     #[serde(default)]
     pub range_step: Vec<RangeStep>,
     pub scene_num: Option<i32>,
@@ -261,6 +331,105 @@ pub struct Zone {
     pub ccs_supported: Option<Vec<String>>,
 }
 
+impl Zone {
+    pub fn range_step(&self, id: &str) -> Option<&RangeStep> {
+        self.range_step.iter().find(|r| r.id == id)
+    }
+
+    /// Checks `value` against the range advertised under `id`.
+    /// Fails if the zone does not advertise such a range at all.
+    pub fn validate_range(&self, id: &str, value: f32) -> Result<f32, Error> {
+        match self.range_step(id) {
+            Some(range) => range.validate(value),
+            None => Err(Error::InvalidArgument(format!(
+                "zone {} has no {} range",
+                self.id, id
+            ))),
+        }
+    }
+
+    /// Clamps `value` into the range advertised under `id`, or returns it as is
+    /// if the zone does not advertise one.
+    pub fn clamp_range(&self, id: &str, value: f32) -> f32 {
+        self.range_step(id).map_or(value, |r| r.clamp(value))
+    }
+
+    /// Checks that `value` is one of the entries of the zone's `list_name` feature list.
+    pub(crate) fn validate_in_list(
+        &self,
+        list_name: &str,
+        list: Option<&Vec<String>>,
+        value: &str,
+    ) -> Result<(), Error> {
+        match list {
+            Some(l) if l.iter().any(|v| v == value) => Ok(()),
+            _ => Err(Error::InvalidArgument(format!(
+                "{} is not in the {} of zone {}",
+                value, list_name, self.id
+            ))),
+        }
+    }
+
+    /// Decoder types from `surr_decoder_type_list`, unknown ones as [`SurroundDecoderType::Other`].
+    pub fn surr_decoder_types(&self) -> Vec<SurroundDecoderType> {
+        self.surr_decoder_type_list
+            .iter()
+            .flatten()
+            .map(|t| {
+                t.parse()
+                    .unwrap_or_else(|_| SurroundDecoderType::Other(t.clone()))
+            })
+            .collect()
+    }
+
+    pub fn validate_scene(&self, num: u32) -> Result<(), Error> {
+        match self.scene_num {
+            Some(max) if num >= 1 && num as i32 <= max => Ok(()),
+            Some(max) => Err(Error::InvalidArgument(format!(
+                "scene {} is outside of 1..={} for zone {}",
+                num, max, self.id
+            ))),
+            None => Err(Error::InvalidArgument(format!(
+                "zone {} has no scenes",
+                self.id
+            ))),
+        }
+    }
+
+    pub fn supports_actual_volume_mode(&self, mode: &str) -> bool {
+        self.actual_volume_mode_list
+            .as_ref()
+            .is_some_and(|l| l.iter().any(|m| m == mode))
+    }
+
+    /// Converts a raw volume step into a 0.0 - 100.0 percentage of the volume range.
+    pub fn volume_to_percent(&self, volume: i32) -> Option<f32> {
+        let range = self.range_step("volume")?;
+        Some(range.fraction(volume as f32) * 100.0)
+    }
+
+    /// Converts a 0.0 - 100.0 percentage into the nearest raw volume step.
+    pub fn percent_to_volume(&self, percent: f32) -> Option<i32> {
+        let range = self.range_step("volume")?;
+        Some(range.at_fraction(percent / 100.0).round() as i32)
+    }
+
+    /// Converts a raw volume step into dB.
+    /// Assumes the `volume` range maps linearly onto the `actual_volume_db` range.
+    pub fn volume_to_db(&self, volume: i32) -> Option<f32> {
+        let volume_range = self.range_step("volume")?;
+        let db_range = self.range_step("actual_volume_db")?;
+        Some(db_range.at_fraction(volume_range.fraction(volume as f32)))
+    }
+
+    /// Converts dB into the nearest raw volume step, see [`Zone::volume_to_db`].
+    pub fn db_to_volume(&self, db: f32) -> Option<i32> {
+        let volume_range = self.range_step("volume")?;
+        let db_range = self.range_step("actual_volume_db")?;
+        Some(volume_range.at_fraction(db_range.fraction(db)).round() as i32)
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct RangeStep {
     pub id: String,
@@ -269,6 +438,54 @@ pub struct RangeStep {
     pub step: f32,
 }
 
+impl RangeStep {
+    /// Returns true if `value` lies within the range and on a step boundary.
+    pub fn contains(&self, value: f32) -> bool {
+        if value < self.min || value > self.max {
+            return false;
+        }
+        if self.step <= 0.0 {
+            return true;
+        }
+        let steps = (value - self.min) / self.step;
+        (steps - steps.round()).abs() < 1e-3
+    }
+
+    /// Clamps `value` into the range and snaps it to the nearest step.
+    pub fn clamp(&self, value: f32) -> f32 {
+        let value = value.max(self.min).min(self.max);
+        if self.step <= 0.0 {
+            return value;
+        }
+        let snapped = self.min + ((value - self.min) / self.step).round() * self.step;
+        snapped.min(self.max)
+    }
+
+    pub fn validate(&self, value: f32) -> Result<f32, Error> {
+        if self.contains(value) {
+            Ok(value)
+        } else {
+            Err(Error::InvalidArgument(format!(
+                "{} {} is outside of {}..={} (step {})",
+                self.id, value, self.min, self.max, self.step
+            )))
+        }
+    }
+
+    /// Position of `value` within the range, from 0.0 to 1.0.
+    fn fraction(&self, value: f32) -> f32 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((self.clamp(value) - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Inverse of [`RangeStep::fraction`], snapped to the nearest step.
+    fn at_fraction(&self, fraction: f32) -> f32 {
+        self.clamp(self.min + fraction.clamp(0.0, 1.0) * (self.max - self.min))
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct Tuner {
     pub func_list: Vec<String>,
@@ -276,6 +493,32 @@ pub struct Tuner {
     pub preset: TunerPreset,
 }
 
+impl Tuner {
+    pub fn range_step(&self, id: &str) -> Option<&TunerRangeStep> {
+        self.range_step.iter().find(|r| r.id == id)
+    }
+
+    pub fn supports_band(&self, band: TunerBand) -> bool {
+        self.func_list.contains(&band.to_string())
+    }
+
+    /// Checks a direct tuning frequency against the band's advertised range.
+    pub fn validate_freq(&self, band: TunerBand, freq: u32) -> Result<(), Error> {
+        let range = self.range_step(&band.to_string()).ok_or_else(|| {
+            Error::InvalidArgument(format!("tuner has no {} frequency range", band))
+        })?;
+        let freq = freq as i32;
+        let on_step = range.step <= 0 || (freq - range.min) % range.step == 0;
+        if freq < range.min || freq > range.max || !on_step {
+            return Err(Error::InvalidArgument(format!(
+                "{} frequency {} is outside of {}..={} (step {})",
+                band, freq, range.min, range.max, range.step
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct TunerRangeStep {
     pub id: String,
@@ -290,6 +533,40 @@ pub struct TunerPreset {
     pub num: i32,
 }
 
+impl TunerPreset {
+    /// `common` presets are shared by all bands, `separate` ones are stored per band.
+    pub fn is_common(&self) -> bool {
+        self.r#type == "common"
+    }
+
+    /// Checks that `band` matches the preset type (`None` for common presets)
+    /// and that `num` is within the advertised preset count.
+    pub fn validate(&self, band: Option<TunerBand>, num: u32) -> Result<(), Error> {
+        match (self.is_common(), band) {
+            (true, Some(b)) => {
+                return Err(Error::InvalidArgument(format!(
+                    "tuner presets are common, got band {}",
+                    b
+                )));
+            }
+            (false, None) => {
+                return Err(Error::InvalidArgument(
+                    "tuner presets are stored per band, a band is required".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        if num < 1 || num as i32 > self.num {
+            return Err(Error::InvalidArgument(format!(
+                "tuner preset {} is outside of 1..={}",
+                num, self.num
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct NetUsb {
     pub func_list: Vec<String>,
@@ -307,6 +584,18 @@ pub struct NetUsbPreset {
     pub num: i32,
 }
 
+impl NetUsbPreset {
+    pub fn validate(&self, num: u32) -> Result<(), Error> {
+        if num < 1 || num as i32 > self.num {
+            return Err(Error::InvalidArgument(format!(
+                "net/usb preset {} is outside of 1..={}",
+                num, self.num
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct NetUsbRecentInfo {
     pub num: i32,
@@ -323,6 +612,19 @@ pub struct NetUsbMcPlaylist {
     pub num: i32,
 }
 
+impl NetUsbMcPlaylist {
+    /// Playlist banks are numbered from 1 to `num`.
+    pub fn validate_bank(&self, bank: u32) -> Result<(), Error> {
+        if bank < 1 || bank as i32 > self.num {
+            return Err(Error::InvalidArgument(format!(
+                "MusicCast playlist {} is outside of 1..={}",
+                bank, self.num
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct NetUsbTidal {
     pub mode: String,
@@ -359,6 +661,43 @@ pub struct McRole {
     pub surround_pair_lr: Option<bool>,
 }
 
+impl McRole {
+    pub fn supports(&self, pair: McPairType) -> bool {
+        let flag = match pair {
+            McPairType::SurroundPair => self.surround_pair,
+            McPairType::StereoPair => self.stereo_pair,
+            McPairType::SubwooferPair => self.subwoofer_pair,
+            McPairType::SurroundPairLOrR => self.surround_pair_l_or_r,
+            McPairType::SurroundPairLr => self.surround_pair_lr,
+            McPairType::Unknown => None,
+        };
+        flag.unwrap_or(false)
+    }
+}
+
+impl Distribution {
+    /// Checks that the device advertises `pair` for the master or slave role.
+    pub fn validate_mc_pair(&self, pair: McPairType, as_master: bool) -> Result<(), Error> {
+        let supported = self.mc_surround.as_ref().is_some_and(|mc| {
+            let role = if as_master {
+                &mc.master_role
+            } else {
+                &mc.slave_role
+            };
+            role.supports(pair)
+        });
+
+        if !supported {
+            return Err(Error::InvalidArgument(format!(
+                "device can not be a {} of a {}",
+                if as_master { "master" } else { "slave" },
+                pair
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct Ccs {
     pub supported: bool,
@@ -368,14 +707,14 @@ pub struct Ccs {
 pub struct NetUsbPlayInfo {
     pub input: String,
     pub play_queue_type: Option<String>,
-    pub playback: String,
-    pub repeat: String,
-    pub shuffle: String,
+    pub playback: PlaybackState,
+    pub repeat: Repeat,
+    pub shuffle: Shuffle,
 
     #[serde(default)]
-    pub repeat_available: Option<Vec<String>>,
+    pub repeat_available: Option<Vec<Repeat>>,
     #[serde(default)]
-    pub shuffle_available: Option<Vec<String>>,
+    pub shuffle_available: Option<Vec<Shuffle>>,
 
     pub play_time: i32,
     pub total_time: i32,
@@ -395,6 +734,62 @@ pub struct NetUsbPlayInfo {
     pub attribute: u32,
 }
 
+impl NetUsbPlayInfo {
+    pub fn flags(&self) -> PlayInfoFlags {
+        PlayInfoFlags::from_bits(self.attribute)
+    }
+}
+
+/// Operations the current content allows, decoded from `NetUsbPlayInfo.attribute`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlayInfoFlags {
+    pub playable: bool,
+    pub stop: bool,
+    pub pause: bool,
+    pub skip_previous: bool,
+    pub skip_next: bool,
+    pub fast_reverse: bool,
+    pub fast_forward: bool,
+    pub repeat: bool,
+    pub shuffle: bool,
+    pub thumbs_up: bool,
+    pub thumbs_down: bool,
+    pub add_to_mc_playlist: bool,
+}
+
+impl PlayInfoFlags {
+    pub fn from_bits(attribute: u32) -> Self {
+        // attributes are bit flags (netusb/getPlayInfo):
+        // b[0]: Playable
+        // b[1]: Stop
+        // b[2]: Pause
+        // b[3]: Prev
+        // b[4]: Next
+        // b[5]: Fast Reverse
+        // b[6]: Fast Forward
+        // b[7]: Repeat
+        // b[8]: Shuffle
+        // b[10]: Thumbs Up
+        // b[11]: Thumbs Down
+        // b[26]: Add to MusicCast Playlist
+        let bit = |n: u32| attribute & (1 << n) != 0;
+        PlayInfoFlags {
+            playable: bit(0),
+            stop: bit(1),
+            pause: bit(2),
+            skip_previous: bit(3),
+            skip_next: bit(4),
+            fast_reverse: bit(5),
+            fast_forward: bit(6),
+            repeat: bit(7),
+            shuffle: bit(8),
+            thumbs_up: bit(10),
+            thumbs_down: bit(11),
+            add_to_mc_playlist: bit(26),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ListInfo {
     pub input: String,
@@ -416,10 +811,36 @@ pub struct ListItem {
     // b[1]: Selectable (Folder/Container)
     // b[2]: Playable
     // b[3]: Searchable
+    // b[4]: Has thumbnail
     #[serde(default)]
     pub subtexts: Vec<String>,
 }
 
+impl ListItem {
+    pub fn flags(&self) -> ListItemFlags {
+        ListItemFlags::from_bits(self.attribute)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListItemFlags {
+    pub selectable: bool,
+    pub playable: bool,
+    pub searchable: bool,
+    pub has_thumbnail: bool,
+}
+
+impl ListItemFlags {
+    pub fn from_bits(attribute: u32) -> Self {
+        ListItemFlags {
+            selectable: attribute & (1 << 1) != 0,
+            playable: attribute & (1 << 2) != 0,
+            searchable: attribute & (1 << 3) != 0,
+            has_thumbnail: attribute & (1 << 4) != 0,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SearchRequest {
     pub list_id: String, // Usually "main"
@@ -437,14 +858,715 @@ pub struct YpaoConfig {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RecentInfo {
-    recent_info: Vec<RecentInfoEntry>,
+    pub recent_info: Vec<RecentInfoEntry>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RecentInfoEntry {
-    input: String,
-    text: String,
-    albumart_url: String,
-    play_count: i32,
-    attribute: u32,
+    pub input: String,
+    pub text: String,
+    pub albumart_url: String,
+    pub play_count: i32,
+    pub attribute: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NameText {
+    #[serde(default)]
+    pub zone_list: Vec<NameTextEntry>,
+    #[serde(default)]
+    pub input_list: Vec<NameTextEntry>,
+    #[serde(default)]
+    pub sound_program_list: Vec<NameTextEntry>,
+    #[serde(default)]
+    pub scene_list: Vec<NameTextEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NameTextEntry {
+    pub id: String,
+    pub text: String,
+}
+
+impl NameText {
+    /// Finds the name of scene `num` of `zone`. Scene ids are either `scene1` or `main_scene1`.
+    pub fn scene_name(&self, zone: &str, num: u32) -> Option<&str> {
+        let zone_id = format!("{}_scene{}", zone, num);
+        let id = format!("scene{}", num);
+        self.scene_list
+            .iter()
+            .find(|e| e.id == zone_id)
+            .or_else(|| self.scene_list.iter().find(|e| e.id == id))
+            .map(|e| e.text.as_str())
+    }
+
+    /// Display name of a zone, or the id itself if the device did not report one.
+    pub fn zone_name<'a>(&'a self, id: &'a str) -> &'a str {
+        lookup_name(&self.zone_list, id)
+    }
+
+    /// Display name of an input such as `hdmi3`, or the id itself.
+    pub fn input_name<'a>(&'a self, id: &'a str) -> &'a str {
+        lookup_name(&self.input_list, id)
+    }
+
+    /// Display name of a sound program, or the id itself.
+    pub fn sound_program_name<'a>(&'a self, id: &'a str) -> &'a str {
+        lookup_name(&self.sound_program_list, id)
+    }
+
+    /// Pairs the zone's inputs with their display names, in feature order.
+    pub fn zone_input_names<'a>(&'a self, zone: &'a Zone) -> Vec<(&'a str, &'a str)> {
+        zone.input_list
+            .iter()
+            .map(|id| (id.as_str(), self.input_name(id)))
+            .collect()
+    }
+
+    /// Display names of the input and sound program reported in a zone status.
+    pub fn status_names<'a>(&'a self, status: &'a ZoneStatus) -> ZoneStatusNames<'a> {
+        ZoneStatusNames {
+            input: self.input_name(&status.input),
+            sound_program: self.sound_program_name(&status.sound_program),
+        }
+    }
+}
+
+fn lookup_name<'a>(list: &'a [NameTextEntry], id: &'a str) -> &'a str {
+    list.iter()
+        .find(|e| e.id == id && !e.text.is_empty())
+        .map_or(id, |e| e.text.as_str())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZoneStatusNames<'a> {
+    pub input: &'a str,
+    pub sound_program: &'a str,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NameTextRequest {
+    pub id: String,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Scene {
+    pub num: u32,
+    /// User configured name, if the firmware exposes scene names.
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TunerPlayInfo {
+    pub band: TunerBand,
+    #[serde(default)]
+    pub auto_scan: bool,
+    #[serde(default)]
+    pub auto_preset: bool,
+    pub am: Option<TunerAm>,
+    pub fm: Option<TunerFm>,
+    pub rds: Option<TunerRds>,
+    pub dab: Option<TunerDab>,
+}
+
+impl TunerPlayInfo {
+    /// Name of the current station: the DAB service label or the RDS program service.
+    pub fn station_name(&self) -> Option<&str> {
+        let name = match self.band {
+            TunerBand::Dab => self.dab.as_ref().map(|d| d.service_label.trim()),
+            TunerBand::Fm => self.rds.as_ref().map(|r| r.program_service.trim()),
+            _ => None,
+        };
+        name.filter(|n| !n.is_empty())
+    }
+
+    /// Text sent along with the broadcast: the DAB DLS or the RDS radio text.
+    pub fn radio_text(&self) -> Option<&str> {
+        match self.band {
+            TunerBand::Dab => self
+                .dab
+                .as_ref()
+                .map(|d| d.dls.trim())
+                .filter(|t| !t.is_empty()),
+            TunerBand::Fm => self.rds.as_ref().and_then(TunerRds::radio_text),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TunerAm {
+    pub preset: i32,
+    /// Frequency in kHz.
+    pub freq: u32,
+    pub tuned: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TunerFm {
+    pub preset: i32,
+    /// Frequency in kHz.
+    pub freq: u32,
+    pub tuned: bool,
+    #[serde(default)]
+    pub audio_mode: TunerAudioMode,
+}
+
+impl TunerFm {
+    pub fn is_stereo(&self) -> bool {
+        self.audio_mode == TunerAudioMode::Stereo
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TunerRds {
+    #[serde(default)]
+    pub program_type: String,
+    #[serde(default)]
+    pub program_service: String,
+    #[serde(default)]
+    pub radio_text_a: String,
+    #[serde(default)]
+    pub radio_text_b: String,
+}
+
+impl TunerRds {
+    /// Radio text A, falling back to radio text B when A is empty.
+    pub fn radio_text(&self) -> Option<&str> {
+        [self.radio_text_a.trim(), self.radio_text_b.trim()]
+            .into_iter()
+            .find(|t| !t.is_empty())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct TunerDab {
+    pub preset: i32,
+    pub id: u32,
+    pub status: DabStatus,
+    /// Frequency in kHz.
+    pub freq: u32,
+    pub category: DabCategory,
+    pub audio_mode: TunerAudioMode,
+    /// Bit rate in kbps.
+    pub bit_rate: u32,
+    /// Signal quality, 0 - 100.
+    pub quality: u32,
+    pub tune_aid: u32,
+    pub off_air: bool,
+    pub dab_plus: bool,
+    pub program_type: String,
+    pub ch_label: String,
+    pub service_label: String,
+    /// Dynamic label segment, the DAB equivalent of RDS radio text.
+    pub dls: String,
+    pub ensemble_label: String,
+    pub initial_scan_progress: u32,
+    pub total_station_num: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TunerPresetInfo {
+    pub preset_info: Vec<TunerPresetEntry>,
+    #[serde(default)]
+    pub func_list: Vec<String>,
+}
+
+/// Preset slot, the position in the list is the preset number minus one.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TunerPresetEntry {
+    pub band: TunerBand,
+    /// Frequency in kHz for AM/FM, service id for DAB, 0 if the slot is empty.
+    pub number: u32,
+}
+
+impl TunerPresetEntry {
+    pub fn is_empty(&self) -> bool {
+        self.number == 0
+    }
+
+    pub fn freq(&self) -> Option<u32> {
+        matches!(self.band, TunerBand::Am | TunerBand::Fm).then_some(self.number)
+    }
+
+    pub fn dab_service_id(&self) -> Option<u32> {
+        (self.band == TunerBand::Dab).then_some(self.number)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NetUsbPresetInfo {
+    pub preset_info: Vec<NetUsbPresetEntry>,
+    #[serde(default)]
+    pub func_list: Vec<String>,
+}
+
+/// Preset slot, the position in the list is the preset number minus one.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NetUsbPresetEntry {
+    pub input: String,
+    pub text: String,
+    #[serde(default)]
+    pub attribute: u32,
+}
+
+impl NetUsbPresetEntry {
+    /// Empty slots are reported with the `unknown` input.
+    pub fn is_empty(&self) -> bool {
+        self.input == "unknown" || self.input.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PlayQueue {
+    pub index: u32,
+    pub max_line: u32,
+    pub playing_index: i32, // -1 if nothing in the queue is playing
+    #[serde(default)]
+    pub track_info: Vec<PlayQueueEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PlayQueueEntry {
+    pub text: String,
+    pub artist: String,
+    pub album: String,
+    pub thumbnail: Option<String>,
+    pub attribute: u32,
+}
+
+/// Parameters for `managePlay`. `list_id` and `index` address a list entry for
+/// the play/add types and a queue entry for `remove`/`move`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ManagePlayRequest {
+    pub r#type: ManagePlay,
+    pub list_id: Option<String>,
+    pub index: Option<u32>,
+    pub to_index: Option<u32>,
+    pub zone: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct McPlaylistNames {
+    /// Playlist names, the position in the list is the bank number minus one.
+    pub name_list: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct McPlaylist {
+    pub bank: u32,
+    pub index: u32,
+    pub max_line: u32,
+    #[serde(default)]
+    pub track_info: Vec<McPlaylistEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct McPlaylistEntry {
+    pub input: String,
+    pub text: String,
+    pub attribute: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct McPlaylistRequest {
+    pub bank: u32,
+    pub r#type: McPlaylistAction,
+    /// Playlist entry for `play`, `delete` and `move`, list entry for `add`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_index: Option<u32>,
+    /// List the `add` entry comes from, usually "main".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+}
+
+impl McPlaylistRequest {
+    pub fn new(bank: u32, r#type: McPlaylistAction) -> Self {
+        McPlaylistRequest {
+            bank,
+            r#type,
+            index: None,
+            to_index: None,
+            list_id: None,
+            name: None,
+            zone: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AccountStatus {
+    #[serde(default)]
+    pub service_list: Vec<ServiceAccount>,
+}
+
+impl AccountStatus {
+    pub fn service(&self, id: &str) -> Option<&ServiceAccount> {
+        self.service_list.iter().find(|s| s.id == id)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ServiceAccount {
+    /// Input id of the service, e.g. `pandora` or `spotify`.
+    pub id: String,
+    pub registered: bool,
+    pub login_status: String,
+    pub username: String,
+    pub r#type: String,
+    pub trial_status: String,
+    pub trial_time_left: i32,
+}
+
+/// Response of `getServiceInfo`, only the block of the requested service is present.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ServiceInfo {
+    pub pandora: Option<ServiceDetails>,
+    pub rhapsody: Option<ServiceDetails>,
+    pub napster: Option<ServiceDetails>,
+    pub siriusxm: Option<ServiceDetails>,
+    pub spotify: Option<ServiceDetails>,
+    pub juke: Option<ServiceDetails>,
+    pub radiko: Option<ServiceDetails>,
+    pub qobuz: Option<ServiceDetails>,
+    pub tidal: Option<ServiceDetails>,
+    pub deezer: Option<ServiceDetails>,
+    pub amazon_music: Option<ServiceDetails>,
+}
+
+impl ServiceInfo {
+    pub fn service(&self, input: &str) -> Option<&ServiceDetails> {
+        match input {
+            "pandora" => self.pandora.as_ref(),
+            "rhapsody" => self.rhapsody.as_ref(),
+            "napster" => self.napster.as_ref(),
+            "siriusxm" => self.siriusxm.as_ref(),
+            "spotify" => self.spotify.as_ref(),
+            "juke" => self.juke.as_ref(),
+            "radiko" => self.radiko.as_ref(),
+            "qobuz" => self.qobuz.as_ref(),
+            "tidal" => self.tidal.as_ref(),
+            "deezer" => self.deezer.as_ref(),
+            "amazon_music" => self.amazon_music.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ServiceDetails {
+    pub account_list: Vec<ServiceAccountEntry>,
+    pub licensing: Option<ServiceLicensing>,
+    pub activation_code: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ServiceAccountEntry {
+    pub name: String,
+    pub timeout: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ServiceLicensing {
+    pub status: String,
+    /// Prompt to show before the service can be used for the first time.
+    pub text: String,
+    pub url: String,
+}
+
+/// Response of `netusb/getSettings`, services missing on the device are `None`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NetUsbSettings {
+    pub auto_play: Option<bool>,
+    pub qobuz: Option<QualitySetting>,
+    pub deezer: Option<QualitySetting>,
+    pub tidal: Option<QualitySetting>,
+    pub amazon_music: Option<QualitySetting>,
+}
+
+impl NetUsbSettings {
+    pub fn quality(&self, input: &str) -> Option<&QualitySetting> {
+        match input {
+            "qobuz" => self.qobuz.as_ref(),
+            "deezer" => self.deezer.as_ref(),
+            "tidal" => self.tidal.as_ref(),
+            "amazon_music" => self.amazon_music.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct QualitySetting {
+    pub quality: StreamingQuality,
+    #[serde(default)]
+    pub quality_list: Vec<StreamingQuality>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PlayDescription {
+    #[serde(default)]
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CdPlayInfo {
+    pub device_status: CdDeviceStatus,
+    pub playback: PlaybackState,
+    pub repeat: Repeat,
+    pub shuffle: Shuffle,
+    pub play_time: i32,
+    pub total_time: i32,
+    #[serde(default)]
+    pub disc_time: i32,
+    pub track_number: u32,
+    pub total_tracks: u32,
+
+    // CD-Text, empty for discs without it
+    #[serde(default)]
+    pub artist: String,
+    #[serde(default)]
+    pub album: String,
+    #[serde(default)]
+    pub track: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DistributionInfo {
+    /// 32 hex digits, all zeros when the device is not in a group.
+    pub group_id: String,
+    #[serde(default)]
+    pub group_name: String,
+    pub role: DistributionRole,
+    pub server_zone: Option<String>,
+    #[serde(default)]
+    pub client_list: Vec<DistributionClient>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DistributionClient {
+    pub ip_address: String,
+    #[serde(default)]
+    pub data_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ServerInfoRequest {
+    pub group_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ServerInfoType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub client_list: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClientInfoRequest {
+    pub group_id: String,
+    pub zone: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_ip_address: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GroupNameRequest {
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn play_info_flags_follow_spec_layout() {
+        // playable, pause, next, repeat, thumbs down and add to MusicCast playlist
+        let attribute = (1 << 0) | (1 << 2) | (1 << 4) | (1 << 7) | (1 << 11) | (1 << 26);
+        assert_eq!(
+            PlayInfoFlags::from_bits(attribute),
+            PlayInfoFlags {
+                playable: true,
+                pause: true,
+                skip_next: true,
+                repeat: true,
+                thumbs_down: true,
+                add_to_mc_playlist: true,
+                ..PlayInfoFlags::default()
+            }
+        );
+    }
+
+    fn range(id: &str, min: f32, max: f32, step: f32) -> RangeStep {
+        RangeStep {
+            id: id.to_string(),
+            min,
+            max,
+            step,
+        }
+    }
+
+    #[test]
+    fn surround_decoder_types_keep_every_id() {
+        let ids = [
+            "toggle",
+            "auto",
+            "dolby_pl",
+            "dolby_pl2x_movie",
+            "dolby_pl2x_music",
+            "dolby_pl2x_game",
+            "dolby_surround",
+            "dts_neural_x",
+            "dts_neo6_cinema",
+            "dts_neo6_music",
+            "auro_3d",
+        ];
+        let zone = Zone {
+            surr_decoder_type_list: Some(ids.iter().map(|id| id.to_string()).collect()),
+            ..Zone::default()
+        };
+
+        let types = zone.surr_decoder_types();
+        assert_eq!(types.len(), ids.len());
+        assert_eq!(types[3], SurroundDecoderType::DolbyPl2xMovie);
+        assert_eq!(types[8], SurroundDecoderType::DtsNeo6Cinema);
+        assert_eq!(types[10], SurroundDecoderType::Other("auro_3d".to_string()));
+
+        for (id, t) in ids.iter().zip(&types) {
+            assert_eq!(t.to_string(), *id);
+            let json = format!("\"{}\"", id);
+            assert_eq!(
+                serde_json::from_str::<SurroundDecoderType>(&json).unwrap(),
+                *t
+            );
+            assert_eq!(serde_json::to_string(t).unwrap(), json);
+        }
+    }
+
+    fn zone() -> Zone {
+        Zone {
+            id: "main".to_string(),
+            range_step: vec![
+                range("volume", 0.0, 161.0, 1.0),
+                range("actual_volume_db", -80.5, 16.5, 0.5),
+            ],
+            ..Zone::default()
+        }
+    }
+
+    #[test]
+    fn range_contains_half_db_steps() {
+        let db = range("actual_volume_db", -80.5, 16.5, 0.5);
+        assert!(db.contains(-80.5));
+        assert!(db.contains(-40.0));
+        assert!(db.contains(-39.5));
+        assert!(db.contains(16.5));
+        assert!(db.validate(0.0).is_ok());
+    }
+
+    #[test]
+    fn range_rejects_off_step_and_out_of_range() {
+        let db = range("actual_volume_db", -80.5, 16.5, 0.5);
+        assert!(!db.contains(-40.25));
+        assert!(!db.contains(-81.0));
+        assert!(!db.contains(17.0));
+        assert!(matches!(db.validate(-40.2), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn range_clamp_snaps_to_step() {
+        let db = range("actual_volume_db", -80.5, 16.5, 0.5);
+        assert_eq!(db.clamp(-40.2), -40.0);
+        assert_eq!(db.clamp(-40.3), -40.5);
+        assert_eq!(db.clamp(-100.0), -80.5);
+        assert_eq!(db.clamp(20.0), 16.5);
+    }
+
+    #[test]
+    fn zone_validate_range_requires_advertised_range() {
+        let zone = zone();
+        assert!(zone.validate_range("volume", 50.0).is_ok());
+        assert!(zone.validate_range("volume", 162.0).is_err());
+        assert!(zone.validate_range("balance", 0.0).is_err());
+        assert_eq!(zone.clamp_range("balance", 3.0), 3.0);
+    }
+
+    #[test]
+    fn volume_conversion_endpoints() {
+        let zone = zone();
+        assert_eq!(zone.volume_to_percent(0), Some(0.0));
+        assert_eq!(zone.volume_to_percent(161), Some(100.0));
+        assert_eq!(zone.volume_to_db(0), Some(-80.5));
+        assert_eq!(zone.volume_to_db(161), Some(16.5));
+        assert_eq!(zone.db_to_volume(-200.0), Some(0));
+        assert_eq!(zone.percent_to_volume(150.0), Some(161));
+    }
+
+    #[test]
+    fn volume_percent_db_round_trip() {
+        let zone = zone();
+        for volume in 0..=161 {
+            let percent = zone.volume_to_percent(volume).unwrap();
+            assert_eq!(zone.percent_to_volume(percent), Some(volume));
+
+            let db = zone.volume_to_db(volume).unwrap();
+            assert!(zone.range_step("actual_volume_db").unwrap().contains(db));
+            assert_eq!(zone.db_to_volume(db), Some(volume));
+        }
+    }
+
+    #[test]
+    fn volume_conversion_without_ranges() {
+        let zone = Zone::default();
+        assert_eq!(zone.volume_to_percent(10), None);
+        assert_eq!(zone.db_to_volume(-20.0), None);
+    }
+
+    #[test]
+    fn mc_pair_validation_uses_advertised_roles() {
+        let dist = Distribution {
+            mc_surround: Some(McSurround {
+                master_role: McRole {
+                    surround_pair: Some(true),
+                    stereo_pair: Some(true),
+                    ..McRole::default()
+                },
+                slave_role: McRole {
+                    surround_pair: Some(true),
+                    ..McRole::default()
+                },
+                ..McSurround::default()
+            }),
+            ..Distribution::default()
+        };
+
+        assert!(dist.validate_mc_pair(McPairType::StereoPair, true).is_ok());
+        assert!(
+            dist.validate_mc_pair(McPairType::StereoPair, false)
+                .is_err()
+        );
+        assert!(
+            dist.validate_mc_pair(McPairType::SurroundPair, false)
+                .is_ok()
+        );
+        assert!(dist.validate_mc_pair(McPairType::Unknown, true).is_err());
+        assert!(
+            Distribution::default()
+                .validate_mc_pair(McPairType::SurroundPair, true)
+                .is_err()
+        );
+    }
 }